@@ -0,0 +1,30 @@
+use sha1::Sha1;
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+
+// md_type ids shared with validate_signature_rsa, which follow mbedtls_md_type_t
+pub const MD_SHA1: u8 = 4;
+pub const MD_SHA224: u8 = 5;
+pub const MD_SHA256: u8 = 6;
+pub const MD_SHA384: u8 = 7;
+pub const MD_SHA512: u8 = 8;
+
+pub const MAX_DIGEST_LEN: usize = 64;
+
+/// Returns the digest of message in the first len bytes of the buffer
+pub fn digest(md_type: u8, message: &[u8]) -> Option<([u8; MAX_DIGEST_LEN], usize)> {
+    let mut result = [0u8; MAX_DIGEST_LEN];
+    let len = match md_type {
+        MD_SHA1 => copy_digest(&mut result, &Sha1::digest(message)),
+        MD_SHA224 => copy_digest(&mut result, &Sha224::digest(message)),
+        MD_SHA256 => copy_digest(&mut result, &Sha256::digest(message)),
+        MD_SHA384 => copy_digest(&mut result, &Sha384::digest(message)),
+        MD_SHA512 => copy_digest(&mut result, &Sha512::digest(message)),
+        _ => return None,
+    };
+    Some((result, len))
+}
+
+fn copy_digest(buf: &mut [u8; MAX_DIGEST_LEN], digest: &[u8]) -> usize {
+    buf[..digest.len()].copy_from_slice(digest);
+    digest.len()
+}
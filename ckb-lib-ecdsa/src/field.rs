@@ -0,0 +1,228 @@
+/// Large enough for the 384-bit curves
+pub const MAX_LIMBS: usize = 6;
+
+/// Little-endian 64-bit limbs, the limbs beyond the modulus length are always zero
+pub type Limbs = [u64; MAX_LIMBS];
+
+/// Arithmetic modulo an odd number, with elements in Montgomery form (a * R mod m, R = 2^(64 * len))
+pub struct Field {
+    m: Limbs,
+    len: usize,
+    m_inv: u64,
+    r2: Limbs,
+}
+
+impl Field {
+    /// The modulus is big-endian and its length must be a multiple of 8 bytes
+    pub fn new(modulus: &[u8]) -> Self {
+        let len = modulus.len() / 8;
+        let m = from_be_bytes(modulus);
+
+        // -m^-1 mod 2^64 by Newton iteration
+        let mut inv = 1u64;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(m[0].wrapping_mul(inv)));
+        }
+
+        let mut field = Field {
+            m,
+            len,
+            m_inv: inv.wrapping_neg(),
+            r2: [0u64; MAX_LIMBS],
+        };
+
+        // R^2 mod m, doubling 1 (which is < m) 2 * 64 * len times
+        let mut r2 = [0u64; MAX_LIMBS];
+        r2[0] = 1;
+        for _ in 0..(128 * len) {
+            r2 = field.add(&r2, &r2);
+        }
+        field.r2 = r2;
+        field
+    }
+
+    pub fn modulus(&self) -> &Limbs {
+        &self.m
+    }
+
+    /// a + b mod m, where a, b < m
+    pub fn add(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let mut result = [0u64; MAX_LIMBS];
+        let mut carry = 0u64;
+        for i in 0..self.len {
+            let sum = a[i] as u128 + b[i] as u128 + carry as u128;
+            result[i] = sum as u64;
+            carry = (sum >> 64) as u64;
+        }
+        if carry != 0 || !less_than(&result, &self.m) {
+            sub_assign(&mut result, &self.m, self.len);
+        }
+        result
+    }
+
+    /// a - b mod m, where a, b < m
+    pub fn sub(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let mut result = *a;
+        if sub_assign(&mut result, b, self.len) {
+            add_assign(&mut result, &self.m, self.len);
+        }
+        result
+    }
+
+    pub fn neg(&self, a: &Limbs) -> Limbs {
+        self.sub(&[0u64; MAX_LIMBS], a)
+    }
+
+    /// Montgomery multiplication a * b / R mod m
+    pub fn mul(&self, a: &Limbs, b: &Limbs) -> Limbs {
+        let len = self.len;
+        let mut t = [0u64; MAX_LIMBS + 2];
+        for i in 0..len {
+            let mut carry = 0u64;
+            for j in 0..len {
+                let sum = t[j] as u128 + a[j] as u128 * b[i] as u128 + carry as u128;
+                t[j] = sum as u64;
+                carry = (sum >> 64) as u64;
+            }
+            let sum = t[len] as u128 + carry as u128;
+            t[len] = sum as u64;
+            t[len + 1] = (sum >> 64) as u64;
+
+            let k = t[0].wrapping_mul(self.m_inv);
+            let sum = t[0] as u128 + k as u128 * self.m[0] as u128;
+            let mut carry = (sum >> 64) as u64;
+            for j in 1..len {
+                let sum = t[j] as u128 + k as u128 * self.m[j] as u128 + carry as u128;
+                t[j - 1] = sum as u64;
+                carry = (sum >> 64) as u64;
+            }
+            let sum = t[len] as u128 + carry as u128;
+            t[len - 1] = sum as u64;
+            t[len] = t[len + 1] + (sum >> 64) as u64;
+        }
+
+        let mut result = [0u64; MAX_LIMBS];
+        result[..len].copy_from_slice(&t[..len]);
+        if t[len] != 0 || !less_than(&result, &self.m) {
+            sub_assign(&mut result, &self.m, len);
+        }
+        result
+    }
+
+    pub fn square(&self, a: &Limbs) -> Limbs {
+        self.mul(a, a)
+    }
+
+    /// Converts a number below 2^(64 * len) into Montgomery form
+    pub fn to_montgomery(&self, a: &Limbs) -> Limbs {
+        self.mul(a, &self.r2)
+    }
+
+    pub fn from_montgomery(&self, a: &Limbs) -> Limbs {
+        let mut one = [0u64; MAX_LIMBS];
+        one[0] = 1;
+        self.mul(a, &one)
+    }
+
+    pub fn one(&self) -> Limbs {
+        let mut one = [0u64; MAX_LIMBS];
+        one[0] = 1;
+        self.to_montgomery(&one)
+    }
+
+    /// a^exponent in Montgomery form, the exponent is a plain number
+    pub fn pow(&self, a: &Limbs, exponent: &Limbs) -> Limbs {
+        let mut result = self.one();
+        for i in (0..(64 * self.len)).rev() {
+            result = self.square(&result);
+            if bit(exponent, i) {
+                result = self.mul(&result, a);
+            }
+        }
+        result
+    }
+
+    /// a^-1 by Fermat's little theorem, the modulus must be prime
+    pub fn invert(&self, a: &Limbs) -> Limbs {
+        let mut exponent = self.m;
+        let mut two = [0u64; MAX_LIMBS];
+        two[0] = 2;
+        sub_assign(&mut exponent, &two, self.len);
+        self.pow(a, &exponent)
+    }
+
+    /// Square root for primes p = 3 mod 4, which holds for every supported curve
+    pub fn sqrt(&self, a: &Limbs) -> Option<Limbs> {
+        let mut exponent = self.m;
+        let mut one = [0u64; MAX_LIMBS];
+        one[0] = 1;
+        add_assign(&mut exponent, &one, self.len);
+        shift_right(&mut exponent, 2);
+        let root = self.pow(a, &exponent);
+        if self.square(&root) == *a {
+            Some(root)
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads big-endian bytes of at most 8 * MAX_LIMBS bytes
+pub fn from_be_bytes(bytes: &[u8]) -> Limbs {
+    let mut result = [0u64; MAX_LIMBS];
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        result[i / 8] |= (*byte as u64) << (8 * (i % 8));
+    }
+    result
+}
+
+pub fn is_zero(a: &Limbs) -> bool {
+    a.iter().all(|limb| *limb == 0)
+}
+
+pub fn is_odd(a: &Limbs) -> bool {
+    a[0] & 1 == 1
+}
+
+pub fn bit(a: &Limbs, index: usize) -> bool {
+    (a[index / 64] >> (index % 64)) & 1 == 1
+}
+
+pub fn less_than(a: &Limbs, b: &Limbs) -> bool {
+    for i in (0..MAX_LIMBS).rev() {
+        if a[i] != b[i] {
+            return a[i] < b[i];
+        }
+    }
+    false
+}
+
+/// a -= b over the lowest len limbs, returns the borrow
+pub fn sub_assign(a: &mut Limbs, b: &Limbs, len: usize) -> bool {
+    let mut borrow = 0u64;
+    for i in 0..len {
+        let (diff, borrow1) = a[i].overflowing_sub(b[i]);
+        let (diff, borrow2) = diff.overflowing_sub(borrow);
+        a[i] = diff;
+        borrow = (borrow1 || borrow2) as u64;
+    }
+    borrow != 0
+}
+
+/// a += b over the lowest len limbs, returns the carry
+pub fn add_assign(a: &mut Limbs, b: &Limbs, len: usize) -> bool {
+    let mut carry = 0u64;
+    for i in 0..len {
+        let sum = a[i] as u128 + b[i] as u128 + carry as u128;
+        a[i] = sum as u64;
+        carry = (sum >> 64) as u64;
+    }
+    carry != 0
+}
+
+fn shift_right(a: &mut Limbs, bits: usize) {
+    for i in 0..MAX_LIMBS {
+        let high = if i + 1 < MAX_LIMBS { a[i + 1] << (64 - bits) } else { 0 };
+        a[i] = (a[i] >> bits) | high;
+    }
+}
@@ -0,0 +1,84 @@
+#![no_std]
+
+mod curves;
+mod digest;
+mod field;
+mod point;
+#[cfg(test)]
+mod tests;
+
+pub use curves::{CURVE_BRAINPOOL_P256R1, CURVE_BRAINPOOL_P384R1, CURVE_P256, CURVE_P384};
+pub use digest::{digest, MAX_DIGEST_LEN, MD_SHA1, MD_SHA224, MD_SHA256, MD_SHA384, MD_SHA512};
+
+use point::{Curve, Point};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    UnsupportedCurve,
+    UnsupportedDigest,
+    InvalidPubKey,
+    InvalidSignature,
+    VerifyFailed,
+}
+
+/// Returns the length in bytes of a field element of the curve, the compressed
+/// public key is one byte longer and the plain r || s signature twice as long
+pub fn get_field_len(curve_id: u8) -> Option<usize> {
+    curves::get_curve(curve_id).map(|curve| curve.field_len())
+}
+
+pub struct PublicKey {
+    curve: Curve,
+    point: Point,
+}
+
+impl PublicKey {
+    /// Loads a SEC1 compressed point, which is checked to be on the curve
+    pub fn from_compressed(curve_id: u8, bytes: &[u8]) -> Result<Self, Error> {
+        let params = curves::get_curve(curve_id).ok_or(Error::UnsupportedCurve)?;
+        let curve = Curve::new(params);
+        let point = curve.decompress(bytes).ok_or(Error::InvalidPubKey)?;
+        Ok(PublicKey { curve, point })
+    }
+
+    /// Verifies a plain (BSI TR-03111) r || s signature of message, hashed with md_type
+    pub fn verify(&self, md_type: u8, message: &[u8], signature: &[u8]) -> Result<(), Error> {
+        let curve = &self.curve;
+        let len = curve.params.field_len();
+        if signature.len() != len * 2 {
+            return Err(Error::InvalidSignature);
+        }
+        let n = curve.fn_.modulus();
+        let r = field::from_be_bytes(&signature[..len]);
+        let s = field::from_be_bytes(&signature[len..]);
+        if field::is_zero(&r) || field::is_zero(&s) || !field::less_than(&r, n) || !field::less_than(&s, n) {
+            return Err(Error::InvalidSignature);
+        }
+
+        // The order of every supported curve is exactly 8 * len bits long, so the
+        // digest is truncated to its leftmost len bytes and is then below 2n
+        let (digest, digest_len) = digest::digest(md_type, message).ok_or(Error::UnsupportedDigest)?;
+        let mut e = field::from_be_bytes(&digest[..core::cmp::min(digest_len, len)]);
+        if !field::less_than(&e, n) {
+            field::sub_assign(&mut e, n, field::MAX_LIMBS);
+        }
+
+        let fn_ = &curve.fn_;
+        let w = fn_.invert(&fn_.to_montgomery(&s));
+        let u1 = fn_.mul(&e, &w);
+        let u2 = fn_.mul(&r, &w);
+
+        let point = curve.mul_base_add(&u1, &u2, &self.point);
+        if point.is_infinity() {
+            return Err(Error::VerifyFailed);
+        }
+        let mut x = curve.affine_x(&point);
+        if !field::less_than(&x, n) {
+            field::sub_assign(&mut x, n, field::MAX_LIMBS);
+        }
+        if x != r {
+            return Err(Error::VerifyFailed);
+        }
+        Ok(())
+    }
+}
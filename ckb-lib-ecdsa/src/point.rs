@@ -0,0 +1,151 @@
+use crate::curves::CurveParams;
+use crate::field::{self, Field, Limbs, MAX_LIMBS};
+
+/// Point in Jacobian coordinates (X / Z^2, Y / Z^3) with Montgomery form coordinates, Z = 0 is the infinity
+#[derive(Clone, Copy)]
+pub struct Point {
+    x: Limbs,
+    y: Limbs,
+    z: Limbs,
+}
+
+impl Point {
+    fn infinity() -> Self {
+        Point {
+            x: [0u64; MAX_LIMBS],
+            y: [0u64; MAX_LIMBS],
+            z: [0u64; MAX_LIMBS],
+        }
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        field::is_zero(&self.z)
+    }
+}
+
+pub struct Curve {
+    pub params: &'static CurveParams,
+    /// Base field GF(p)
+    pub fp: Field,
+    /// Scalar field GF(n)
+    pub fn_: Field,
+    a: Limbs,
+    b: Limbs,
+    g: Point,
+}
+
+impl Curve {
+    pub fn new(params: &'static CurveParams) -> Self {
+        let fp = Field::new(params.p);
+        let fn_ = Field::new(params.n);
+        let a = fp.to_montgomery(&field::from_be_bytes(params.a));
+        let b = fp.to_montgomery(&field::from_be_bytes(params.b));
+        let g = Point {
+            x: fp.to_montgomery(&field::from_be_bytes(params.gx)),
+            y: fp.to_montgomery(&field::from_be_bytes(params.gy)),
+            z: fp.one(),
+        };
+        Curve { params, fp, fn_, a, b, g }
+    }
+
+    /// Decodes a SEC1 compressed point 0x02 / 0x03 || X
+    pub fn decompress(&self, bytes: &[u8]) -> Option<Point> {
+        let len = self.params.field_len();
+        if bytes.len() != len + 1 || (bytes[0] != 0x02 && bytes[0] != 0x03) {
+            return None;
+        }
+        let x = field::from_be_bytes(&bytes[1..]);
+        if !field::less_than(&x, self.fp.modulus()) {
+            return None;
+        }
+
+        // y^2 = x^3 + ax + b
+        let fp = &self.fp;
+        let x = fp.to_montgomery(&x);
+        let rhs = fp.add(&fp.mul(&fp.add(&fp.square(&x), &self.a), &x), &self.b);
+        let mut y = fp.sqrt(&rhs)?;
+        if field::is_odd(&fp.from_montgomery(&y)) != (bytes[0] == 0x03) {
+            y = fp.neg(&y);
+        }
+        Some(Point { x, y, z: fp.one() })
+    }
+
+    /// dbl-2007-bl, valid for any a
+    pub fn double(&self, p: &Point) -> Point {
+        if p.is_infinity() {
+            return *p;
+        }
+        let fp = &self.fp;
+        let xx = fp.square(&p.x);
+        let yy = fp.square(&p.y);
+        let yyyy = fp.square(&yy);
+        let zz = fp.square(&p.z);
+        let s = fp.sub(&fp.sub(&fp.square(&fp.add(&p.x, &yy)), &xx), &yyyy);
+        let s = fp.add(&s, &s);
+        let m = fp.add(&fp.add(&fp.add(&xx, &xx), &xx), &fp.mul(&self.a, &fp.square(&zz)));
+        let t = fp.sub(&fp.sub(&fp.square(&m), &s), &s);
+        let yyyy8 = fp.add(&yyyy, &yyyy);
+        let yyyy8 = fp.add(&yyyy8, &yyyy8);
+        let yyyy8 = fp.add(&yyyy8, &yyyy8);
+        let y3 = fp.sub(&fp.mul(&m, &fp.sub(&s, &t)), &yyyy8);
+        let z3 = fp.sub(&fp.sub(&fp.square(&fp.add(&p.y, &p.z)), &yy), &zz);
+        Point { x: t, y: y3, z: z3 }
+    }
+
+    /// add-2007-bl
+    pub fn add(&self, p: &Point, q: &Point) -> Point {
+        if p.is_infinity() {
+            return *q;
+        }
+        if q.is_infinity() {
+            return *p;
+        }
+        let fp = &self.fp;
+        let z1z1 = fp.square(&p.z);
+        let z2z2 = fp.square(&q.z);
+        let u1 = fp.mul(&p.x, &z2z2);
+        let u2 = fp.mul(&q.x, &z1z1);
+        let s1 = fp.mul(&fp.mul(&p.y, &q.z), &z2z2);
+        let s2 = fp.mul(&fp.mul(&q.y, &p.z), &z1z1);
+        let h = fp.sub(&u2, &u1);
+        let r = fp.sub(&s2, &s1);
+        if field::is_zero(&h) {
+            if field::is_zero(&r) {
+                return self.double(p);
+            }
+            return Point::infinity();
+        }
+        let i = fp.square(&fp.add(&h, &h));
+        let j = fp.mul(&h, &i);
+        let r = fp.add(&r, &r);
+        let v = fp.mul(&u1, &i);
+        let x3 = fp.sub(&fp.sub(&fp.sub(&fp.square(&r), &j), &v), &v);
+        let s1j = fp.mul(&s1, &j);
+        let y3 = fp.sub(&fp.sub(&fp.mul(&r, &fp.sub(&v, &x3)), &s1j), &s1j);
+        let z3 = fp.mul(&fp.sub(&fp.sub(&fp.square(&fp.add(&p.z, &q.z)), &z1z1), &z2z2), &h);
+        Point { x: x3, y: y3, z: z3 }
+    }
+
+    /// u1 * G + u2 * Q with Shamir's trick, u1 and u2 are plain scalars
+    pub fn mul_base_add(&self, u1: &Limbs, u2: &Limbs, q: &Point) -> Point {
+        let g_q = self.add(&self.g, q);
+        let mut result = Point::infinity();
+        for i in (0..(8 * self.params.field_len())).rev() {
+            result = self.double(&result);
+            match (field::bit(u1, i), field::bit(u2, i)) {
+                (true, true) => result = self.add(&result, &g_q),
+                (true, false) => result = self.add(&result, &self.g),
+                (false, true) => result = self.add(&result, q),
+                (false, false) => {}
+            }
+        }
+        result
+    }
+
+    /// Plain affine x coordinate of a point which is not the infinity
+    pub fn affine_x(&self, p: &Point) -> Limbs {
+        let fp = &self.fp;
+        let z_inv = fp.invert(&p.z);
+        fp.from_montgomery(&fp.mul(&p.x, &fp.square(&z_inv)))
+    }
+}
@@ -0,0 +1,181 @@
+extern crate std;
+
+use super::*;
+use std::vec::Vec;
+
+fn hex(s: &str) -> Vec<u8> {
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).unwrap())
+        .collect()
+}
+
+fn signature(r: &str, s: &str) -> Vec<u8> {
+    let mut signature = hex(r);
+    signature.extend(hex(s));
+    signature
+}
+
+// RFC 6979 A.2.5, P-256 key x = C9AFA9D8...120F6721
+const P256_PUB_KEY: &str = "0360fed4ba255a9d31c961eb74c6356d68c049b8923b61fa6ce669622e60f29fb6";
+// The order n of P-256
+const P256_N: &str = "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551";
+
+struct Vector {
+    curve_id: u8,
+    md_type: u8,
+    pub_key: &'static str,
+    r: &'static str,
+    s: &'static str,
+}
+
+const VECTORS: [Vector; 6] = [
+    // RFC 6979 A.2.5, SHA-256 and message "sample"
+    Vector {
+        curve_id: CURVE_P256,
+        md_type: MD_SHA256,
+        pub_key: P256_PUB_KEY,
+        r: "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716",
+        s: "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8",
+    },
+    // RFC 6979 A.2.5, SHA-1 is shorter than the order
+    Vector {
+        curve_id: CURVE_P256,
+        md_type: MD_SHA1,
+        pub_key: P256_PUB_KEY,
+        r: "61340c88c3aaebeb4f6d667f672ca9759a6ccaa9fa8811313039ee4a35471d32",
+        s: "6d7f147dac089441bb2e2fe8f7a3fa264b9c475098fdcf6e00d7c996e1b8b7eb",
+    },
+    // RFC 6979 A.2.5, SHA-512 is truncated to the order length
+    Vector {
+        curve_id: CURVE_P256,
+        md_type: MD_SHA512,
+        pub_key: P256_PUB_KEY,
+        r: "8496a60b5e9b47c825488827e0495b0e3fa109ec4568fd3f8d1097678eb97f00",
+        s: "2362ab1adbe2b8adf9cb9edab740ea6049c028114f2460f96554f61fae3302fe",
+    },
+    // RFC 6979 A.2.6, SHA-384 and message "sample"
+    Vector {
+        curve_id: CURVE_P384,
+        md_type: MD_SHA384,
+        pub_key: "02ec3a4e415b4e19a4568618029f427fa5da9a8bc4ae92e02e06aae5286b300c64def8f0ea9055866064a254515480bc13",
+        r: "94edbb92a5ecb8aad4736e56c691916b3f88140666ce9fa73d64c4ea95ad133c81a648152e44acf96e36dd1e80fabe46",
+        s: "99ef4aeb15f178cea1fe40db2603138f130e740a19624526203b6351d0a3a94fa329c145786e679e7b82c71a38628ac8",
+    },
+    // RFC 6979 deterministic signature of "sample" by OpenSSL, key x = 0C9AFA9D...B120F672
+    Vector {
+        curve_id: CURVE_BRAINPOOL_P256R1,
+        md_type: MD_SHA256,
+        pub_key: "0230d965775bd9a94050cd75c8b18920b1f0c51f3d32bd1a0f7f3cc8391a8fde1b",
+        r: "57d1a27b4afcbcb2d561dae6de609c24759fdcce2c938adc8350a6cca402b293",
+        s: "1d002e526b33cbbebe0dae5717f03b3b0ba8faf8951e407affb3c667fda819c5",
+    },
+    // RFC 6979 deterministic signature of "sample" by OpenSSL, key x = 06B9D3DA...60D2EDF
+    Vector {
+        curve_id: CURVE_BRAINPOOL_P384R1,
+        md_type: MD_SHA384,
+        pub_key: "0267046c6699b40f1e2d83ce040009977f536dee594218b56c880df7b02f6c472067a3081cfddec22003b518772d3418da",
+        r: "76c42f9734fffe03b529ab8c2aed542206e7ef7e6d547dd083f3314214e0cdfa0e75c2286dce9a173913421dc685b569",
+        s: "0e8d8d2c77aab2b16679bdaf2b149a2ed9b28c34aae9a60288707d1d2971bf3c3584dfe76e974146ec0374379fb99aa4",
+    },
+];
+
+#[test]
+fn test_known_answers() {
+    for vector in VECTORS.iter() {
+        let pub_key = PublicKey::from_compressed(vector.curve_id, &hex(vector.pub_key)).expect("load pub key");
+        let signature = signature(vector.r, vector.s);
+        assert_eq!(pub_key.verify(vector.md_type, b"sample", &signature), Ok(()));
+        assert_eq!(
+            pub_key.verify(vector.md_type, b"test", &signature),
+            Err(Error::VerifyFailed)
+        );
+
+        let mut tampered = signature.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            pub_key.verify(vector.md_type, b"sample", &tampered),
+            Err(Error::VerifyFailed)
+        );
+    }
+}
+
+#[test]
+fn test_invalid_pub_key() {
+    let pub_key = hex(P256_PUB_KEY);
+
+    // Uncompressed points, the SEC1 infinity and wrong lengths are not accepted
+    let mut uncompressed = pub_key.clone();
+    uncompressed[0] = 0x04;
+    assert!(PublicKey::from_compressed(CURVE_P256, &uncompressed).is_err());
+    assert!(PublicKey::from_compressed(CURVE_P256, &[0x00]).is_err());
+    assert!(PublicKey::from_compressed(CURVE_P256, &pub_key[..32]).is_err());
+    assert!(PublicKey::from_compressed(CURVE_P384, &pub_key).is_err());
+
+    // x = 1 is not the x coordinate of a P-256 point
+    let mut off_curve = [0u8; 33];
+    off_curve[0] = 0x02;
+    off_curve[32] = 0x01;
+    assert_eq!(
+        PublicKey::from_compressed(CURVE_P256, &off_curve).err(),
+        Some(Error::InvalidPubKey)
+    );
+
+    // x = p is out of the field
+    let mut out_of_field = hex("02ffffffff00000001000000000000000000000000ffffffffffffffffffffffff");
+    assert_eq!(
+        PublicKey::from_compressed(CURVE_P256, &out_of_field).err(),
+        Some(Error::InvalidPubKey)
+    );
+    out_of_field[0] = 0x03;
+    assert!(PublicKey::from_compressed(CURVE_P256, &out_of_field).is_err());
+
+    assert_eq!(
+        PublicKey::from_compressed(0, &pub_key).err(),
+        Some(Error::UnsupportedCurve)
+    );
+}
+
+#[test]
+fn test_signature_out_of_range() {
+    let pub_key = PublicKey::from_compressed(CURVE_P256, &hex(P256_PUB_KEY)).unwrap();
+    let r = "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716";
+    let s = "f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8";
+    let zero = "0000000000000000000000000000000000000000000000000000000000000000";
+    let all_ones = "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff";
+
+    for (r, s) in [(zero, s), (r, zero), (P256_N, s), (r, P256_N), (all_ones, s), (r, all_ones)].iter() {
+        assert_eq!(
+            pub_key.verify(MD_SHA256, b"sample", &signature(r, s)),
+            Err(Error::InvalidSignature)
+        );
+    }
+
+    let signature = signature(r, s);
+    assert_eq!(
+        pub_key.verify(MD_SHA256, b"sample", &signature[1..]),
+        Err(Error::InvalidSignature)
+    );
+    assert_eq!(
+        pub_key.verify(0, b"sample", &signature),
+        Err(Error::UnsupportedDigest)
+    );
+}
+
+#[test]
+fn test_point_at_infinity() {
+    // With Q = G and s = 1, r = n - e gives u1 * G + u2 * Q = (e + r) * G = infinity
+    let pub_key = PublicKey::from_compressed(
+        CURVE_P256,
+        &hex("036b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
+    )
+    .unwrap();
+    let signature = signature(
+        "50d4241d5564913f1d521e296b0be038a263ddab3e2e156f91a88d3899b55392",
+        "0000000000000000000000000000000000000000000000000000000000000001",
+    );
+    assert_eq!(
+        pub_key.verify(MD_SHA256, b"sample", &signature),
+        Err(Error::VerifyFailed)
+    );
+}
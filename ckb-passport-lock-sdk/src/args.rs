@@ -0,0 +1,89 @@
+//! Lock args of ckb-passport-lock, the layout and the rules on the flags are those of the
+//! contract, shared through ckb-passport-lock-types.
+
+use ckb_passport_lock_types::args::{encode_flags, parse_flags, FEATURES};
+pub use ckb_passport_lock_types::args::{
+    check_flags, ArgsError, FLAG_ACP, FLAG_BACKUP_KEY, FLAG_DELEGATION, FLAG_DOCUMENT, FLAG_DOCUMENT_REVEAL,
+    FLAG_EPOCH_SPENDING_LIMIT, FLAG_GRACE_CLAIMED, FLAG_GUARDIANS, FLAG_INHERITANCE, FLAG_MIN_DIGEST, FLAG_MULTISIG,
+    FLAG_PASSIVE_AUTH, FLAG_RECOVERY_PENDING, FLAG_REVOCATION, FLAG_ROTATION_GRACE, FLAG_SINCE, FLAG_TX_SPENDING_LIMIT,
+    FLAG_UNVAULTING, FLAG_VAULT, FLAG_WHITELIST, PUB_KEY_HASH_LEN,
+};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LockArgs {
+    pub pub_key_hash: [u8; PUB_KEY_HASH_LEN],
+    /// Payloads of the features, indexed as FEATURES
+    payloads: Vec<Option<Vec<u8>>>,
+}
+
+impl LockArgs {
+    pub fn new(pub_key_hash: [u8; PUB_KEY_HASH_LEN]) -> Self {
+        LockArgs {
+            pub_key_hash,
+            payloads: vec![None; FEATURES.len()],
+        }
+    }
+
+    /// Parses args as the contract does, returns None for args the contract rejects
+    pub fn parse(args: &[u8]) -> Option<Self> {
+        let (flags, mut offset) = parse_flags(args).ok()?;
+        let mut pub_key_hash = [0u8; PUB_KEY_HASH_LEN];
+        pub_key_hash.copy_from_slice(&args[..PUB_KEY_HASH_LEN]);
+
+        let mut lock_args = LockArgs::new(pub_key_hash);
+        for (i, (flag, payload_len)) in FEATURES.iter().enumerate() {
+            if flags & flag != 0 {
+                lock_args.payloads[i] = Some(args[offset..(offset + payload_len)].to_vec());
+                offset += payload_len;
+            }
+        }
+        Some(lock_args)
+    }
+
+    /// Checks the flags as the contract does
+    pub fn check(&self) -> Result<(), ArgsError> {
+        check_flags(self.flags())
+    }
+
+    pub fn flags(&self) -> u32 {
+        FEATURES
+            .iter()
+            .zip(self.payloads.iter())
+            .filter(|(_, payload)| payload.is_some())
+            .fold(0, |flags, ((flag, _), _)| flags | flag)
+    }
+
+    pub fn payload(&self, flag: u32) -> Option<&[u8]> {
+        let index = FEATURES.iter().position(|(feature, _)| *feature == flag)?;
+        self.payloads[index].as_deref()
+    }
+
+    /// Sets a flag with its payload, panics if the flag is unknown or the payload has not
+    /// the length of the feature
+    pub fn set_payload(&mut self, flag: u32, payload: &[u8]) {
+        let index = FEATURES
+            .iter()
+            .position(|(feature, _)| *feature == flag)
+            .expect("known flag");
+        assert_eq!(payload.len(), FEATURES[index].1, "payload length");
+        self.payloads[index] = Some(payload.to_vec());
+    }
+
+    /// Clears a flag with its payload
+    pub fn clear_flag(&mut self, flag: u32) {
+        if let Some(index) = FEATURES.iter().position(|(feature, _)| *feature == flag) {
+            self.payloads[index] = None;
+        }
+    }
+
+    /// Encodes the args, panics if the contract rejects their flags
+    pub fn to_vec(&self) -> Vec<u8> {
+        self.check().expect("valid flags");
+        let mut args = self.pub_key_hash.to_vec();
+        encode_flags(&mut args, self.flags());
+        for payload in self.payloads.iter().flatten() {
+            args.extend_from_slice(payload);
+        }
+        args
+    }
+}
@@ -0,0 +1,61 @@
+//! Session key delegation. The passport signs a certificate of a secp256k1 session key once,
+//! the certificate lives in a type id cell of the passport lock and the session key then signs
+//! as a secp256k1_blake160_sighash_all lock. The lock does not check the expiry on a session
+//! spend: the delegation stays usable until its cell is consumed, either by the passport or,
+//! once the expiry is reached, by anyone without a signature.
+
+use ckb_hash::{blake2b_256, new_blake2b};
+use ckb_passport_lock_types::{self as passport_types, prelude::*};
+use ckb_types::packed::CellInput;
+
+/// Code hash of the type id script built into CKB, with the type hash type
+pub const TYPE_ID_CODE_HASH: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, b'T', b'Y', b'P', b'E', b'_', b'I', b'D',
+];
+
+/// Returns the type id args of the output at output_index of a transaction whose first input
+/// is first_input
+pub fn type_id(first_input: &CellInput, output_index: u64) -> [u8; 32] {
+    let mut blake2b = new_blake2b();
+    blake2b.update(first_input.as_slice());
+    blake2b.update(&output_index.to_le_bytes());
+    let mut type_id = [0u8; 32];
+    blake2b.finalize(&mut type_id);
+    type_id
+}
+
+/// Builds a DelegationCertificate, the expiry is an absolute since and the spend cap bounds
+/// the net outflow of a transaction in shannons
+pub fn certificate(
+    session_pub_key_hash: [u8; 20],
+    type_id: [u8; 32],
+    expiry: u64,
+    spend_cap: Option<u64>,
+) -> passport_types::DelegationCertificate {
+    passport_types::DelegationCertificate::new_builder()
+        .session_pubkey_hash(session_pub_key_hash.into())
+        .type_id(type_id.into())
+        .expiry(expiry.to_le_bytes().into())
+        .spend_cap(
+            passport_types::Uint64Opt::new_builder()
+                .set(spend_cap.map(|spend_cap| spend_cap.to_le_bytes().into()))
+                .build(),
+        )
+        .build()
+}
+
+/// Returns the message the passport signs for the certificate
+pub fn signing_message(certificate: &passport_types::DelegationCertificate) -> [u8; 32] {
+    blake2b_256(certificate.as_slice())
+}
+
+/// Returns the data of the delegation cell, passport_lock is a witness lock of the passport
+/// signing the message of the certificate
+pub fn cell_data(certificate: passport_types::DelegationCertificate, passport_lock: &[u8]) -> Vec<u8> {
+    passport_types::DelegationCell::new_builder()
+        .certificate(certificate)
+        .passport_lock(passport_types::Bytes::from(passport_lock))
+        .build()
+        .as_slice()
+        .to_vec()
+}
@@ -0,0 +1,30 @@
+//! Document binding of passports. The args commit to the blake2b of a random salt and DG1, the
+//! MRZ data of the document, and may require the passport to reveal them with the
+//! LDSSecurityObject of its EF.SOD, which must be registered by passive authentication.
+
+use ckb_hash::new_blake2b;
+use ckb_passport_lock_types::{self as passport_types, prelude::*};
+
+pub const SALT_LEN: usize = 32;
+
+/// Returns the payload of the document flag
+pub fn commitment(salt: &[u8; SALT_LEN], dg1: &[u8]) -> [u8; 32] {
+    let mut commitment = [0u8; 32];
+    let mut blake2b = new_blake2b();
+    blake2b.update(salt);
+    blake2b.update(dg1);
+    blake2b.finalize(&mut commitment);
+    commitment
+}
+
+/// Builds a DocumentWitness revealing the document around the witness lock of the signatures
+pub fn witness_lock(salt: &[u8; SALT_LEN], dg1: &[u8], security_object: &[u8], lock: &[u8]) -> Vec<u8> {
+    passport_types::DocumentWitness::new_builder()
+        .salt((*salt).into())
+        .dg1(passport_types::Bytes::from(dg1))
+        .security_object(passport_types::Bytes::from(security_object))
+        .lock(passport_types::Bytes::from(lock))
+        .build()
+        .as_slice()
+        .to_vec()
+}
@@ -0,0 +1,10 @@
+//! Host helpers to build transactions of ckb-passport-lock cells.
+
+pub mod args;
+pub mod delegation;
+pub mod document;
+pub mod passive_auth;
+pub mod revocation;
+pub mod rotation;
+pub mod signer;
+pub mod whitelist;
@@ -0,0 +1,40 @@
+//! Registration of passports by passive authentication. A registration cell of
+//! ckb-passport-passive-auth holds the pubkey hash of a passport whose EF.SOD verifies up to a
+//! CSCA of the master list cell.
+
+use ckb_hash::blake2b_256;
+use ckb_passport_lock_types::{self as passport_types, prelude::*};
+
+/// Returns the data of a registration cell, the pubkey hash of the passport, followed by the
+/// blake2b of the LDSSecurityObject, the encapsulated content of the EF.SOD, for a passport
+/// which binds its cells to its document
+pub fn registration_data(pub_key_hash: &[u8; 20], security_object: Option<&[u8]>) -> Vec<u8> {
+    let mut data = pub_key_hash.to_vec();
+    if let Some(security_object) = security_object {
+        data.extend_from_slice(&blake2b_256(security_object));
+    }
+    data
+}
+
+/// Returns the master list cell data of the SubjectPublicKeyInfo DER of the CSCAs
+pub fn master_list_data(cscas: &[Vec<u8>]) -> Vec<u8> {
+    let cscas = cscas.iter().map(|csca| passport_types::Bytes::from(&csca[..])).collect();
+    passport_types::BytesVec::new_builder()
+        .set(cscas)
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+/// Builds the PassiveAuthWitness of a registration cell, the output_type of its witness, from
+/// the EF.SOD and EF.DG15 of the chip and the common header the passport signs with
+pub fn witness(header: [u8; 4], sod: &[u8], dg15: &[u8], csca_index: u32) -> Vec<u8> {
+    passport_types::PassiveAuthWitness::new_builder()
+        .header(header.into())
+        .sod(passport_types::Bytes::from(sod))
+        .dg15(passport_types::Bytes::from(dg15))
+        .csca_index(csca_index.to_le_bytes().into())
+        .build()
+        .as_slice()
+        .to_vec()
+}
@@ -0,0 +1,43 @@
+//! Revocation registry of passports. The registry cell data are the revoked pubkey hashes in
+//! strictly ascending order, a signer proves that it is not revoked with its position, the
+//! number of revoked pubkey hashes below its own.
+//!
+//! The registry cell has the ckb-passport-revocation-registry type script, whose args are the
+//! lock hash of the updater of the registry.
+
+use ckb_passport_lock_types::{self as passport_types, prelude::*};
+
+pub const PUB_KEY_HASH_LEN: usize = 20;
+
+/// Returns the registry cell data of the revoked pubkey hashes
+pub fn registry_data(revoked: &[[u8; PUB_KEY_HASH_LEN]]) -> Vec<u8> {
+    let mut revoked = revoked.to_vec();
+    revoked.sort();
+    revoked.dedup();
+    revoked.concat()
+}
+
+/// Returns the position of a pubkey hash in the registry cell data, None if it is revoked or
+/// the data are not a registry
+pub fn position(registry_data: &[u8], pub_key_hash: &[u8; PUB_KEY_HASH_LEN]) -> Option<u32> {
+    if registry_data.len() % PUB_KEY_HASH_LEN != 0 {
+        return None;
+    }
+    let revoked: Vec<&[u8]> = registry_data.chunks(PUB_KEY_HASH_LEN).collect();
+    match revoked.binary_search(&&pub_key_hash[..]) {
+        Ok(_) => None,
+        Err(position) => Some(position as u32),
+    }
+}
+
+/// Builds a RevocationWitness of the positions of the signers, in signer order, around the
+/// witness lock of the signatures
+pub fn witness_lock(positions: &[u32], lock: &[u8]) -> Vec<u8> {
+    let positions = positions.iter().map(|position| position.to_le_bytes().into()).collect();
+    passport_types::RevocationWitness::new_builder()
+        .positions(passport_types::Uint32Vec::new_builder().set(positions).build())
+        .lock(passport_types::Bytes::from(lock))
+        .build()
+        .as_slice()
+        .to_vec()
+}
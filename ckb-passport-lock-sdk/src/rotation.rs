@@ -0,0 +1,147 @@
+//! Rotation of the cells of an expiring passport to its renewed replacement.
+//!
+//! The old passport signs a transaction which re-creates every one of its cells with the
+//! pubkey hash of the new passport, so no cell is left to the expired document. With a
+//! rotation grace the old passport may instead designate the new one as its successor, which
+//! claims the cells alone after the expiry.
+
+use ckb_types::{
+    bytes::Bytes,
+    core::{Capacity, TransactionBuilder, TransactionView},
+    packed::{Byte32, CellDep, CellInput, CellOutput, OutPoint},
+    prelude::*,
+};
+
+use crate::args::{LockArgs, FLAG_GRACE_CLAIMED, FLAG_RECOVERY_PENDING, FLAG_ROTATION_GRACE, FLAG_VAULT, PUB_KEY_HASH_LEN};
+
+/// A live cell, as found by an indexer
+#[derive(Clone, Debug)]
+pub struct LiveCell {
+    pub out_point: OutPoint,
+    pub output: CellOutput,
+    pub data: Bytes,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    NoCells,
+    InsufficientCapacity,
+    /// A vault only lets the passport unvault its cells
+    VaultedCell,
+}
+
+pub struct Rotation {
+    /// Code hash of the passport lock
+    pub lock_code_hash: Byte32,
+    pub old_pub_key_hash: [u8; PUB_KEY_HASH_LEN],
+    pub new_pub_key_hash: [u8; PUB_KEY_HASH_LEN],
+    /// Absolute since of the new passport expiry, which replaces the old one in the args
+    /// with a rotation grace
+    pub new_expiry: u64,
+}
+
+impl Rotation {
+    /// Returns the cells locked by the old passport
+    pub fn find_cells<'a>(&self, cells: &'a [LiveCell]) -> Vec<&'a LiveCell> {
+        cells.iter().filter(|cell| self.old_args(cell).is_some()).collect()
+    }
+
+    /// Returns the lock args of a cell locked by the old passport
+    fn old_args(&self, cell: &LiveCell) -> Option<LockArgs> {
+        let lock = cell.output.lock();
+        if lock.code_hash() != self.lock_code_hash {
+            return None;
+        }
+        LockArgs::parse(&lock.args().raw_data()).filter(|args| args.pub_key_hash == self.old_pub_key_hash)
+    }
+
+    /// Returns the args with the new pubkey hash, every other feature is kept but a rotation
+    /// grace, which starts over without successor nor claim, and a pending guardian recovery,
+    /// which the old passport cancels by spending the cells. The cells of a vault are refused.
+    pub fn rotate_args(&self, args: &LockArgs) -> Result<LockArgs, Error> {
+        if args.payload(FLAG_VAULT).is_some() {
+            return Err(Error::VaultedCell);
+        }
+        let mut args = args.clone();
+        args.pub_key_hash = self.new_pub_key_hash;
+        if let Some(grace) = args.payload(FLAG_ROTATION_GRACE) {
+            let mut grace = grace.to_vec();
+            grace[..8].copy_from_slice(&self.new_expiry.to_le_bytes());
+            grace[16..].copy_from_slice(&[0u8; PUB_KEY_HASH_LEN]);
+            args.set_payload(FLAG_ROTATION_GRACE, &grace);
+            args.clear_flag(FLAG_GRACE_CLAIMED);
+        }
+        args.clear_flag(FLAG_RECOVERY_PENDING);
+        Ok(args)
+    }
+
+    /// Returns the args of the old passport with the new one designated as the successor of
+    /// its rotation grace, None without a rotation grace
+    pub fn designate_args(&self, args: &LockArgs) -> Option<LockArgs> {
+        let mut grace = args.payload(FLAG_ROTATION_GRACE)?.to_vec();
+        grace[16..].copy_from_slice(&self.new_pub_key_hash);
+        let mut args = args.clone();
+        args.set_payload(FLAG_ROTATION_GRACE, &grace);
+        Some(args)
+    }
+
+    /// Builds the rotation transaction of the cells of the old passport among cells. Every
+    /// one is re-created with the same capacity, type and data under the rotated args, but
+    /// the largest one, which pays the fee. The witnesses are left empty for the old passport
+    /// to sign each script group.
+    pub fn build_tx(&self, cells: &[LiveCell], cell_deps: Vec<CellDep>, fee: u64) -> Result<TransactionView, Error> {
+        let cells: Vec<(&LiveCell, LockArgs)> = cells
+            .iter()
+            .filter_map(|cell| self.old_args(cell).map(|args| (cell, args)))
+            .collect();
+        let payer = cells
+            .iter()
+            .enumerate()
+            .max_by_key(|(_, (cell, _))| Unpack::<u64>::unpack(&cell.output.capacity()))
+            .map(|(index, _)| index)
+            .ok_or(Error::NoCells)?;
+
+        let mut inputs = vec![];
+        let mut outputs = vec![];
+        let mut outputs_data = vec![];
+        for (index, (cell, args)) in cells.iter().enumerate() {
+            let lock = cell
+                .output
+                .lock()
+                .as_builder()
+                .args(Bytes::from(self.rotate_args(args)?.to_vec()).pack())
+                .build();
+            let mut capacity: u64 = cell.output.capacity().unpack();
+            if index == payer {
+                capacity = capacity.checked_sub(fee).ok_or(Error::InsufficientCapacity)?;
+            }
+            let output = cell
+                .output
+                .clone()
+                .as_builder()
+                .lock(lock)
+                .capacity(capacity.pack())
+                .build();
+            let data_capacity = Capacity::bytes(cell.data.len()).map_err(|_| Error::InsufficientCapacity)?;
+            let occupied = output
+                .occupied_capacity(data_capacity)
+                .map_err(|_| Error::InsufficientCapacity)?;
+            if occupied.as_u64() > capacity {
+                return Err(Error::InsufficientCapacity);
+            }
+
+            inputs.push(CellInput::new_builder().previous_output(cell.out_point.clone()).build());
+            outputs.push(output);
+            outputs_data.push(cell.data.pack());
+        }
+        let witnesses = vec![Bytes::new().pack(); inputs.len()];
+
+        Ok(TransactionBuilder::default()
+            .inputs(inputs)
+            .outputs(outputs)
+            .outputs_data(outputs_data)
+            .cell_deps(cell_deps)
+            .witnesses(witnesses)
+            .build())
+    }
+}
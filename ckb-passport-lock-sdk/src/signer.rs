@@ -0,0 +1,190 @@
+//! Host-side signer of passport lock groups, the message follows the contract for every
+//! sighash type.
+
+use ckb_hash::{new_blake2b, Blake2b};
+use ckb_passport_lock_types as passport_types;
+use ckb_types::{
+    bytes::Bytes,
+    core::TransactionView,
+    packed::{self, WitnessArgs},
+    prelude::*,
+};
+
+/// The signatures cover the tx hash, as the witness locks without a sighash type
+pub const SIGHASH_ALL: u8 = 0x01;
+/// The signatures cover the outputs at the indices of the group inputs only
+pub const SIGHASH_SINGLE: u8 = 0x03;
+/// The signatures cover the group inputs only, combined with SIGHASH_ALL or SIGHASH_SINGLE
+pub const SIGHASH_ANYONECANPAY: u8 = 0x80;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Error {
+    UnsupportedSighashType,
+    OutputMissing,
+    InvalidGroup,
+    InvalidWitness,
+    LockLengthMismatch,
+}
+
+/// Builds a PassportLockWitnessV2 witness lock
+pub fn witness_lock(header: [u8; 4], pub_key: &[u8], signatures: &[Vec<u8>], sighash_type: u8) -> Vec<u8> {
+    let signatures = signatures
+        .iter()
+        .map(|signature| passport_types::Bytes::from(&signature[..]))
+        .collect();
+    passport_types::PassportLockWitnessV2::new_builder()
+        .version(passport_types::WITNESS_VERSION_SIGHASH.into())
+        .header(header.into())
+        .pubkey(passport_types::Bytes::from(pub_key))
+        .signatures(passport_types::BytesVec::new_builder().set(signatures).build())
+        .sighash_type(sighash_type.into())
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+/// Builds a PassportLockWitnessV3 witness lock, whose public key is read from the registry cell
+/// at pub_key_dep in the cell deps
+pub fn registry_witness_lock(pub_key_dep: u32, signatures: &[Vec<u8>], sighash_type: u8) -> Vec<u8> {
+    let signatures = signatures
+        .iter()
+        .map(|signature| passport_types::Bytes::from(&signature[..]))
+        .collect();
+    passport_types::PassportLockWitnessV3::new_builder()
+        .version(passport_types::WITNESS_VERSION_REGISTRY.into())
+        .pubkey_dep(pub_key_dep.to_le_bytes().into())
+        .signatures(passport_types::BytesVec::new_builder().set(signatures).build())
+        .sighash_type(sighash_type.into())
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+/// Returns the data of the registry cell of a public key, the blake160 of which is the pubkey
+/// hash of the lock args and the args of the registry type script
+pub fn registry_data(header: [u8; 4], pub_key: &[u8]) -> Vec<u8> {
+    let mut data = header.to_vec();
+    data.extend_from_slice(pub_key);
+    data
+}
+
+/// Computes the message signed for the script group, given as the indices of its inputs.
+/// The lock of the first group witness is digested as witness_lock_len zero bytes.
+pub fn signing_message(
+    tx: &TransactionView,
+    group: &[usize],
+    sighash_type: u8,
+    witness_lock_len: usize,
+) -> Result<[u8; 32], Error> {
+    let first = *group.first().ok_or(Error::InvalidGroup)?;
+    let mut blake2b = new_blake2b();
+    if sighash_type == SIGHASH_ALL {
+        blake2b.update(tx.hash().as_slice());
+    } else {
+        digest_tx(&mut blake2b, tx, group, sighash_type)?;
+    }
+
+    let witness = group_witness_args(tx, first)?
+        .as_builder()
+        .lock(Some(Bytes::from(vec![0u8; witness_lock_len])).pack())
+        .build();
+    digest_witness(&mut blake2b, &witness.as_bytes());
+    let witnesses = tx.witnesses();
+    for index in group[1..].iter() {
+        match witnesses.get(*index) {
+            Some(witness) => digest_witness(&mut blake2b, &witness.raw_data()),
+            None => break,
+        }
+    }
+    if sighash_type == SIGHASH_ALL {
+        for index in tx.inputs().len()..witnesses.len() {
+            digest_witness(&mut blake2b, &witnesses.get(index).expect("witness").raw_data());
+        }
+    }
+
+    let mut message = [0u8; 32];
+    blake2b.finalize(&mut message);
+    Ok(message)
+}
+
+/// Signs the script group. build_lock returns the witness lock of the signatures of the
+/// message, or without a message the lock of zero signatures of the same length. The lock is
+/// set in the witness of the first group input.
+pub fn sign_group<L: Fn(Option<&[u8; 32]>) -> Vec<u8>>(
+    tx: TransactionView,
+    group: &[usize],
+    sighash_type: u8,
+    build_lock: L,
+) -> Result<TransactionView, Error> {
+    let witness_lock_len = build_lock(None).len();
+    let message = signing_message(&tx, group, sighash_type, witness_lock_len)?;
+    let lock = build_lock(Some(&message));
+    if lock.len() != witness_lock_len {
+        return Err(Error::LockLengthMismatch);
+    }
+
+    let first = group[0];
+    let witness = group_witness_args(&tx, first)?
+        .as_builder()
+        .lock(Some(Bytes::from(lock)).pack())
+        .build();
+    let mut witnesses: Vec<packed::Bytes> = tx.witnesses().into_iter().collect();
+    if witnesses.len() <= first {
+        witnesses.resize(first + 1, Bytes::new().pack());
+    }
+    witnesses[first] = witness.as_bytes().pack();
+    Ok(tx.as_advanced_builder().set_witnesses(witnesses).build())
+}
+
+/// A missing or empty witness reads as default WitnessArgs
+fn group_witness_args(tx: &TransactionView, index: usize) -> Result<WitnessArgs, Error> {
+    match tx.witnesses().get(index) {
+        Some(witness) if !witness.raw_data().is_empty() => {
+            WitnessArgs::from_slice(&witness.raw_data()).map_err(|_| Error::InvalidWitness)
+        }
+        _ => Ok(WitnessArgs::default()),
+    }
+}
+
+fn digest_tx(blake2b: &mut Blake2b, tx: &TransactionView, group: &[usize], sighash_type: u8) -> Result<(), Error> {
+    let base_type = sighash_type & !SIGHASH_ANYONECANPAY;
+    if sighash_type == SIGHASH_ALL || (base_type != SIGHASH_ALL && base_type != SIGHASH_SINGLE) {
+        return Err(Error::UnsupportedSighashType);
+    }
+    blake2b.update(&[sighash_type]);
+
+    let inputs = tx.inputs();
+    if sighash_type & SIGHASH_ANYONECANPAY != 0 {
+        for index in group.iter() {
+            blake2b.update(inputs.get(*index).ok_or(Error::InvalidGroup)?.as_slice());
+        }
+    } else {
+        for input in inputs.into_iter() {
+            blake2b.update(input.as_slice());
+        }
+    }
+
+    if base_type == SIGHASH_ALL {
+        for index in 0..tx.outputs().len() {
+            digest_output(blake2b, tx, index)?;
+        }
+    } else {
+        for index in group.iter() {
+            digest_output(blake2b, tx, *index)?;
+        }
+    }
+    Ok(())
+}
+
+fn digest_output(blake2b: &mut Blake2b, tx: &TransactionView, index: usize) -> Result<(), Error> {
+    let (output, data) = tx.output_with_data(index).ok_or(Error::OutputMissing)?;
+    blake2b.update(output.as_slice());
+    blake2b.update(&(data.len() as u64).to_le_bytes());
+    blake2b.update(&data);
+    Ok(())
+}
+
+fn digest_witness(blake2b: &mut Blake2b, witness: &[u8]) {
+    blake2b.update(&(witness.len() as u64).to_le_bytes());
+    blake2b.update(witness);
+}
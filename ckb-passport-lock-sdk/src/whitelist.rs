@@ -0,0 +1,61 @@
+//! Merkle tree of the lock hashes a whitelisted passport may send to. The leaves are the lock
+//! hashes, a node is the blake2b of its children in ascending order and the last node of an
+//! odd level moves up unchanged.
+
+use ckb_hash::blake2b_256;
+use ckb_passport_lock_types::{self as passport_types, prelude::*};
+
+/// Returns the merkle root of the lock hashes, zero for an empty list
+pub fn merkle_root(lock_hashes: &[[u8; 32]]) -> [u8; 32] {
+    let mut level = lock_hashes.to_vec();
+    while level.len() > 1 {
+        level = level.chunks(2).map(parent).collect();
+    }
+    level.first().cloned().unwrap_or([0u8; 32])
+}
+
+/// Returns the siblings from the leaf at index up to the root
+pub fn merkle_proof(lock_hashes: &[[u8; 32]], mut index: usize) -> Vec<[u8; 32]> {
+    let mut proof = vec![];
+    let mut level = lock_hashes.to_vec();
+    while level.len() > 1 {
+        if let Some(sibling) = level.get(index ^ 1) {
+            proof.push(*sibling);
+        }
+        level = level.chunks(2).map(parent).collect();
+        index /= 2;
+    }
+    proof
+}
+
+/// Builds a WhitelistWitness of the proofs of the outputs of other locks, in output order,
+/// around the witness lock of the signatures
+pub fn witness_lock(proofs: &[Vec<[u8; 32]>], lock: &[u8]) -> Vec<u8> {
+    let proofs = proofs
+        .iter()
+        .map(|proof| {
+            passport_types::Byte32Vec::new_builder()
+                .set(proof.iter().map(|node| (*node).into()).collect())
+                .build()
+        })
+        .collect();
+    passport_types::WhitelistWitness::new_builder()
+        .proofs(passport_types::Byte32VecVec::new_builder().set(proofs).build())
+        .lock(passport_types::Bytes::from(lock))
+        .build()
+        .as_slice()
+        .to_vec()
+}
+
+fn parent(children: &[[u8; 32]]) -> [u8; 32] {
+    match children {
+        [left, right] => {
+            let (left, right) = if left <= right { (left, right) } else { (right, left) };
+            let mut data = [0u8; 64];
+            data[..32].copy_from_slice(left);
+            data[32..].copy_from_slice(right);
+            blake2b_256(&data[..])
+        }
+        _ => children[0],
+    }
+}
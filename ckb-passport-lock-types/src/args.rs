@@ -0,0 +1,257 @@
+//! Layout of the ckb-passport-lock args, shared by the contract and the SDK: the 20-byte
+//! pubkey hash, optionally followed by flags bytes and the payloads of the set flags in the
+//! order of FEATURES. Plain 20-byte args have no flag set.
+
+use alloc::vec::Vec;
+
+pub const PUB_KEY_HASH_LEN: usize = 20;
+const LOCK_HASH_LEN: usize = 32;
+const SINCE_LEN: usize = 8;
+
+/// The pubkey hash is the hash of a multisig config
+pub const FLAG_MULTISIG: u32 = 1;
+/// The payload is the blake160 of a secp256k1 backup key
+pub const FLAG_BACKUP_KEY: u32 = 1 << 1;
+/// The payload is the guardian set hash and the recovery delay
+pub const FLAG_GUARDIANS: u32 = 1 << 2;
+/// The payload is a since (u64 little endian) every input of the group must reach
+pub const FLAG_SINCE: u32 = 1 << 3;
+/// The payload is the beneficiary lock hash and the inactivity period
+pub const FLAG_INHERITANCE: u32 = 1 << 4;
+/// The payload is the passport expiry, the claim delay and the designated successor
+pub const FLAG_ROTATION_GRACE: u32 = 1 << 5;
+/// The payload is the minimums of an anyone-can-pay payment
+pub const FLAG_ACP: u32 = 1 << 6;
+/// The payload is the spending limit of a single transaction and the delay beyond it
+pub const FLAG_TX_SPENDING_LIMIT: u32 = 1 << 7;
+/// The payload is the merkle root of the locks the passport may send to
+pub const FLAG_WHITELIST: u32 = 1 << 8;
+/// The payload is the recovery key and the unvaulting delay of a vault
+pub const FLAG_VAULT: u32 = 1 << 9;
+/// State of a vault whose cells are unvaulting, without payload
+pub const FLAG_UNVAULTING: u32 = 1 << 10;
+/// The payload is the type script hash of a revocation registry
+pub const FLAG_REVOCATION: u32 = 1 << 11;
+/// Session keys may unlock with a delegation of the passport, without payload
+pub const FLAG_DELEGATION: u32 = 1 << 12;
+/// The payload is the type script hash of the passive authentication registrations
+pub const FLAG_PASSIVE_AUTH: u32 = 1 << 13;
+/// The payload is the commitment to the salted DG1 of the document
+pub const FLAG_DOCUMENT: u32 = 1 << 14;
+/// The passport must reveal the document of the commitment, without payload
+pub const FLAG_DOCUMENT_REVEAL: u32 = 1 << 15;
+/// State of guardian recovered cells, the payload is the new lock hash
+pub const FLAG_RECOVERY_PENDING: u32 = 1 << 16;
+/// State of cells claimed by the successor of a rotation grace, without payload
+pub const FLAG_GRACE_CLAIMED: u32 = 1 << 17;
+/// The payload is the spending limit of an epoch, the delay beyond it and the type script hash
+/// of the cell which keeps what was spent
+pub const FLAG_EPOCH_SPENDING_LIMIT: u32 = 1 << 18;
+/// The payload is the weakest digest the passport may sign with
+pub const FLAG_MIN_DIGEST: u32 = 1 << 19;
+
+/// Payload of the backup key flag: the blake160 of the secp256k1 backup key
+pub const BACKUP_KEY_PAYLOAD_LEN: usize = PUB_KEY_HASH_LEN;
+/// Payload of the guardians flag: blake160(threshold || count || sorted guardian lock hashes)
+/// and the relative since of the recovery delay (u64 little endian)
+pub const GUARDIANS_PAYLOAD_LEN: usize = 20 + SINCE_LEN;
+/// Payload of the inheritance flag: the beneficiary lock hash and the relative since of the
+/// inactivity period (u64 little endian)
+pub const INHERITANCE_PAYLOAD_LEN: usize = LOCK_HASH_LEN + SINCE_LEN;
+/// Payload of the rotation grace flag: the absolute since of the passport expiry and the
+/// relative since of the claim delay (u64 little endian each), then the pubkey hash of the
+/// successor the passport designated
+pub const ROTATION_GRACE_PAYLOAD_LEN: usize = 2 * SINCE_LEN + PUB_KEY_HASH_LEN;
+/// Payload of the anyone-can-pay flag: the minimums of a payment in CKB and in UDT, as
+/// exponents of 10 like the anyone-can-pay lock
+pub const ACP_PAYLOAD_LEN: usize = 2;
+/// Payload of the transaction spending limit flag: the maximum net outflow of a transaction in
+/// shannons and the relative since of the delay of larger transfers (u64 little endian each)
+pub const TX_SPENDING_LIMIT_PAYLOAD_LEN: usize = 16;
+/// Payload of the whitelist flag: the merkle root of the allowed lock hashes
+pub const WHITELIST_PAYLOAD_LEN: usize = 32;
+/// Payload of the vault flag: the blake160 of the secp256k1 recovery key and the relative
+/// since of the unvaulting delay (u64 little endian)
+pub const VAULT_PAYLOAD_LEN: usize = 20 + SINCE_LEN;
+/// Payload of the revocation flag: the type script hash of the revocation registry cell
+pub const REVOCATION_PAYLOAD_LEN: usize = 32;
+/// Payload of the passive authentication flag: the type script hash of the registration cells,
+/// that is of ckb-passport-passive-auth with the type script hash of a master list as args
+pub const PASSIVE_AUTH_PAYLOAD_LEN: usize = 32;
+/// Payload of the document flag: the blake2b of a 32-byte salt || DG1, the MRZ data of the
+/// document. The salt keeps the MRZ, which has little entropy, from being guessed.
+pub const DOCUMENT_PAYLOAD_LEN: usize = 32;
+/// Payload of the recovery pending flag: the new lock hash
+pub const RECOVERY_PENDING_PAYLOAD_LEN: usize = LOCK_HASH_LEN;
+/// Payload of the epoch spending limit flag: the maximum net outflow of the transactions of an
+/// epoch in shannons and the relative since of the delay of larger transfers (u64 little endian
+/// each), then the type script hash of the type id cell of this lock which keeps what was spent
+pub const EPOCH_SPENDING_LIMIT_PAYLOAD_LEN: usize = 2 * SINCE_LEN + 32;
+/// Payload of the minimum digest flag: the md_type of the common header, SHA-1 (4) to SHA-512
+/// (8), below which the signatures of every algorithm are rejected
+pub const MIN_DIGEST_PAYLOAD_LEN: usize = 1;
+
+/// Every flags byte carries 7 flags, its high bit tells that another flags byte follows
+const FLAGS_CONTINUATION: u8 = 0x80;
+const FLAGS_PER_BYTE: usize = 7;
+const MAX_FLAGS_BYTES: usize = 4;
+
+/// Flags of the limits on the net outflow of the lock
+const SPENDING_LIMITS: u32 = FLAG_TX_SPENDING_LIMIT | FLAG_EPOCH_SPENDING_LIMIT;
+
+/// Optional features as (flag, payload length), the payloads of the set flags follow the
+/// flags bytes in this order
+pub const FEATURES: [(u32, usize); 20] = [
+    (FLAG_MULTISIG, 0),
+    (FLAG_BACKUP_KEY, BACKUP_KEY_PAYLOAD_LEN),
+    (FLAG_GUARDIANS, GUARDIANS_PAYLOAD_LEN),
+    (FLAG_SINCE, SINCE_LEN),
+    (FLAG_INHERITANCE, INHERITANCE_PAYLOAD_LEN),
+    (FLAG_ROTATION_GRACE, ROTATION_GRACE_PAYLOAD_LEN),
+    (FLAG_ACP, ACP_PAYLOAD_LEN),
+    (FLAG_TX_SPENDING_LIMIT, TX_SPENDING_LIMIT_PAYLOAD_LEN),
+    (FLAG_WHITELIST, WHITELIST_PAYLOAD_LEN),
+    (FLAG_VAULT, VAULT_PAYLOAD_LEN),
+    (FLAG_UNVAULTING, 0),
+    (FLAG_REVOCATION, REVOCATION_PAYLOAD_LEN),
+    (FLAG_DELEGATION, 0),
+    (FLAG_PASSIVE_AUTH, PASSIVE_AUTH_PAYLOAD_LEN),
+    (FLAG_DOCUMENT, DOCUMENT_PAYLOAD_LEN),
+    (FLAG_DOCUMENT_REVEAL, 0),
+    (FLAG_RECOVERY_PENDING, RECOVERY_PENDING_PAYLOAD_LEN),
+    (FLAG_GRACE_CLAIMED, 0),
+    (FLAG_EPOCH_SPENDING_LIMIT, EPOCH_SPENDING_LIMIT_PAYLOAD_LEN),
+    (FLAG_MIN_DIGEST, MIN_DIGEST_PAYLOAD_LEN),
+];
+
+/// Why the contract rejects args
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArgsError {
+    /// The args are malformed or combine flags which do not work together
+    Invalid,
+    /// A flag of no known feature is set
+    UnsupportedFlags,
+}
+
+/// Parses the flags of args, checks them and the length of the payloads, returns the flags
+/// and the offset of the payloads
+pub fn parse_flags(args: &[u8]) -> Result<(u32, usize), ArgsError> {
+    if args.len() < PUB_KEY_HASH_LEN {
+        return Err(ArgsError::Invalid);
+    }
+
+    let mut flags = 0u32;
+    let mut offset = PUB_KEY_HASH_LEN;
+    if args.len() > offset {
+        loop {
+            let index = offset - PUB_KEY_HASH_LEN;
+            if index == MAX_FLAGS_BYTES {
+                return Err(ArgsError::Invalid);
+            }
+            let byte = *args.get(offset).ok_or(ArgsError::Invalid)?;
+            flags |= ((byte & !FLAGS_CONTINUATION) as u32) << (FLAGS_PER_BYTE * index);
+            offset += 1;
+            if byte & FLAGS_CONTINUATION == 0 {
+                // The last flags byte of the canonical encoding, as encode_flags writes it,
+                // holds a flag, so the args of a passport have a single lock hash
+                if byte == 0 {
+                    return Err(ArgsError::Invalid);
+                }
+                break;
+            }
+        }
+    }
+
+    check_flags(flags)?;
+    if args.len() != offset + payload_offset(flags, 0) {
+        return Err(ArgsError::Invalid);
+    }
+    Ok((flags, offset))
+}
+
+/// Appends the flags bytes of flags to the pubkey hash
+pub fn encode_flags(args: &mut Vec<u8>, flags: u32) {
+    let mut flags = flags;
+    while flags != 0 {
+        let byte = flags as u8 & !FLAGS_CONTINUATION;
+        flags >>= FLAGS_PER_BYTE;
+        args.push(if flags != 0 { byte | FLAGS_CONTINUATION } else { byte });
+    }
+}
+
+/// Returns the offset of the payload of a flag among the payloads of flags, which need not
+/// include it. The offset of no flag is the length of all the payloads.
+pub fn payload_offset(flags: u32, flag: u32) -> usize {
+    FEATURES
+        .iter()
+        .take_while(|(feature, _)| *feature != flag)
+        .filter(|(feature, _)| flags & feature != 0)
+        .map(|(_, payload_len)| payload_len)
+        .sum()
+}
+
+/// Returns the payload length of a known flag
+pub fn payload_len(flag: u32) -> Option<usize> {
+    FEATURES
+        .iter()
+        .find(|(feature, _)| *feature == flag)
+        .map(|(_, payload_len)| *payload_len)
+}
+
+/// Checks that the flags are known and work together
+pub fn check_flags(flags: u32) -> Result<(), ArgsError> {
+    let known_flags = FEATURES.iter().fold(0, |known_flags, (flag, _)| known_flags | flag);
+    if flags & !known_flags != 0 {
+        return Err(ArgsError::UnsupportedFlags);
+    }
+    if flags & FLAG_UNVAULTING != 0 && flags & FLAG_VAULT == 0 {
+        return Err(ArgsError::Invalid);
+    }
+    // The since constraint applies to every unlock path and requires its own since flags of the
+    // group inputs, while the recovery, inheritance, grace claim, spending limit and unvaulting
+    // delays require theirs, so the cells could never be spent along those paths
+    if flags & FLAG_SINCE != 0
+        && flags & (FLAG_GUARDIANS | FLAG_INHERITANCE | FLAG_ROTATION_GRACE | SPENDING_LIMITS | FLAG_VAULT) != 0
+    {
+        return Err(ArgsError::Invalid);
+    }
+    // The proofs of a whitelist or a revocation registry wrap the passport witness lock, which a
+    // session key does not carry
+    if flags & FLAG_DELEGATION != 0 && flags & (FLAG_WHITELIST | FLAG_REVOCATION) != 0 {
+        return Err(ArgsError::Invalid);
+    }
+    // Neither does the backup key signature, which would otherwise send past the whitelist
+    if flags & FLAG_BACKUP_KEY != 0 && flags & FLAG_WHITELIST != 0 {
+        return Err(ArgsError::Invalid);
+    }
+    // Guardian recovery moves the cells to a lock the passport never approved, which neither a
+    // whitelist proof, an unvaulting delay nor a spending limit could bound
+    if flags & FLAG_GUARDIANS != 0 && flags & (FLAG_WHITELIST | FLAG_VAULT | SPENDING_LIMITS) != 0 {
+        return Err(ArgsError::Invalid);
+    }
+    // Unvaulting moves the group capacity at once to the unvaulting lock, which is derived from
+    // these args so the whitelist cannot commit to it, and a spending limit would cap it
+    if flags & FLAG_VAULT != 0 && flags & (FLAG_WHITELIST | SPENDING_LIMITS) != 0 {
+        return Err(ArgsError::Invalid);
+    }
+    // So does the grace claim request of the successor, whose claimed lock is derived from these
+    // args as well
+    if flags & FLAG_ROTATION_GRACE != 0 && flags & FLAG_WHITELIST != 0 {
+        return Err(ArgsError::Invalid);
+    }
+    if flags & FLAG_RECOVERY_PENDING != 0 && flags & FLAG_GUARDIANS == 0 {
+        return Err(ArgsError::Invalid);
+    }
+    if flags & FLAG_GRACE_CLAIMED != 0 && flags & FLAG_ROTATION_GRACE == 0 {
+        return Err(ArgsError::Invalid);
+    }
+    // A document is revealed against the registration of the one passport of the args, whose
+    // witness lock it wraps
+    if flags & FLAG_DOCUMENT_REVEAL != 0
+        && (flags & (FLAG_DOCUMENT | FLAG_PASSIVE_AUTH) != FLAG_DOCUMENT | FLAG_PASSIVE_AUTH
+            || flags & (FLAG_MULTISIG | FLAG_ROTATION_GRACE | FLAG_DELEGATION) != 0)
+    {
+        return Err(ArgsError::Invalid);
+    }
+    Ok(())
+}
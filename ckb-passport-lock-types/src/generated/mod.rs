@@ -0,0 +1,4 @@
+#![allow(clippy::all)]
+#![allow(unused_imports)]
+
+pub mod passport_lock;
@@ -0,0 +1,54 @@
+//! Molecule types of the ckb-passport-lock witness and the layout of its args.
+//!
+//! The generated readers are no_std and used by the contract, enable the `std`
+//! feature to use the builders on hosts.
+
+#![no_std]
+
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
+
+pub mod args;
+mod generated;
+
+pub use generated::passport_lock::*;
+pub use molecule::{self, prelude};
+
+use prelude::*;
+
+/// Version of PassportLockWitness, a witness lock which is not a PassportLockWitness
+/// is parsed as the legacy raw signatures || header || public key layout
+pub const WITNESS_VERSION: u8 = 1;
+/// Version of PassportLockWitnessV2, which appends the sighash type to PassportLockWitness
+pub const WITNESS_VERSION_SIGHASH: u8 = 2;
+/// Version of PassportLockWitnessV3, which reads the public key from a registry cell dep
+pub const WITNESS_VERSION_REGISTRY: u8 = 3;
+
+impl From<&[u8]> for Bytes {
+    fn from(data: &[u8]) -> Self {
+        Bytes::new_builder()
+            .set(data.iter().map(|byte| Byte::new(*byte)).collect())
+            .build()
+    }
+}
+
+macro_rules! impl_from_byte_array {
+    ($name:ident, $len:expr) => {
+        impl From<[u8; $len]> for $name {
+            fn from(value: [u8; $len]) -> Self {
+                let mut inner = [Byte::new(0); $len];
+                for (byte, value) in inner.iter_mut().zip(value.iter()) {
+                    *byte = Byte::new(*value);
+                }
+                $name::new_builder().set(inner).build()
+            }
+        }
+    };
+}
+
+impl_from_byte_array!(AuthHeader, 4);
+impl_from_byte_array!(Byte20, 20);
+impl_from_byte_array!(Byte32, 32);
+impl_from_byte_array!(Uint32, 4);
+impl_from_byte_array!(Uint64, 8);
@@ -1,6 +1,6 @@
 // Import from `core` instead of from `std` since we are in no-std mode
 use core::result::Result;
-use alloc::vec::Vec;
+use alloc::{vec, vec::Vec};
 
 use ckb_std::{
     ckb_constants::Source,
@@ -8,77 +8,446 @@ use ckb_std::{
     error::SysError,
     dynamic_loading::CKBDLContext,
     ckb_types::{bytes::Bytes, prelude::*},
-    high_level::{load_script, load_witness_args, load_transaction, load_tx_hash},
+    high_level::{load_cell_data, load_script, load_witness_args, load_transaction, load_tx_hash},
+};
+use ckb_passport_lock_types::{
+    AuthHeaderReader, BytesReader, BytesVecReader, PassportLockWitnessReader, PassportLockWitnessV2Reader,
+    PassportLockWitnessV3Reader, RecoveryWitnessReader, WITNESS_VERSION, WITNESS_VERSION_REGISTRY,
+    WITNESS_VERSION_SIGHASH,
 };
 use crate::error::Error;
 
+mod acp;
+mod args;
+mod backup;
+mod delegation;
+mod document;
 mod rsa;
+mod ecdsa;
+mod digest;
 mod hash;
+mod inheritance;
+mod limit;
+mod multisig;
+mod passive_auth;
+mod recovery;
+mod revocation;
+mod rotation;
+mod sighash;
+mod vault;
+mod whitelist;
+mod since;
+
+const MESSAGE_SIZE: usize = 32;
+const COMMON_HEADER: usize = 4;
+const PUBLIC_KEY_E_LEN: usize = 4;
+const WITNESS_BUF_LEN: usize = 32 * 1024;
+
+/// Numbers of signatures a witness lock may carry, the message is split into that many chunks
+const SIGNATURE_COUNTS: [usize; 3] = [4, 2, 1];
+
+/// The third byte of the common header holds the padding in its low nibble and the
+/// chunking of the message in its high nibble
+const PADDING_MASK: u8 = 0x0F;
+const CHUNKING_SHIFT: u8 = 4;
 
-const MESSAGE_SINGLE_SIZE: usize = 8;
-const SIGNATURE_LEN: usize = 512;  // in byte
-const SUB_SIGNATURE_LEN: usize = 128;
-const COMMON_HEADER: usize = 4; 
-const PUBLIC_KEY_E_LEN: usize = 4; 
-const PUBLIC_KEY_N_LEN: usize = 128;
-const SIGNATURE_TOTAL_LEN: usize = 648; 
+const CHUNKING_DEFAULT: u8 = 0;
+const CHUNKING_8: u8 = 1;
+const CHUNKING_16: u8 = 2;
+const CHUNKING_32: u8 = 3;
+
+/// Key family and signature sizes of a legacy witness lock
+struct KeyLayout {
+    ecdsa: bool,
+    sub_signature_len: usize,
+    signature_count: usize,
+}
+
+/// Witness lock fields, read from a PassportLockWitness or from the legacy raw layout
+struct WitnessLock<'a> {
+    ecdsa: bool,
+    header: &'a [u8],
+    pub_key: &'a [u8],
+    signatures: Vec<&'a [u8]>,
+    sighash_type: u8,
+}
+
+/// Signer whose witness lock fields match its header
+struct Auth<'a> {
+    lock: WitnessLock<'a>,
+    key_header: [u8; COMMON_HEADER],
+    chunk_size: usize,
+    pub_key_hash: [u8; 20],
+}
 
 pub fn main() -> Result<(), Error> {
     let script = load_script()?;
     let args: Bytes = script.args().unpack();
-    if args.len() != 20 {
-        return Err(Error::InvalidArgument);
+    let lock_args = args::parse_args(&args)?;
+
+    // The since constraint applies to every unlock path
+    if let Some(since) = lock_args.payload_u64(args::FLAG_SINCE) {
+        since::check_group_since(since)?;
+    }
+
+    // Without a witness anyone may consume expired delegation cells
+    if lock_args.has_flag(args::FLAG_DELEGATION) && acp::is_unsigned()? && delegation::is_delegation_group()? {
+        return delegation::expire();
+    }
+
+    // Without a witness anyone may pay to the cells
+    if lock_args.has_flag(args::FLAG_ACP) && acp::is_unsigned()? {
+        return acp::verify_payment(lock_args.payload(args::FLAG_ACP).ok_or(Error::InvalidArgument)?);
     }
 
     let witness_args = load_witness_args(0, Source::GroupInput)?;
-    let witness: Bytes = witness_args
-          .lock()
-          .to_opt()
-          .ok_or(Error::Encoding)?
-          .unpack();
+    let witness_lock = witness_args.lock().to_opt();
 
-    let mut signature = [0u8; SIGNATURE_LEN];
-    let mut pub_key_e = [0u8; PUBLIC_KEY_E_LEN];
-    let mut pub_key_n = [0u8; PUBLIC_KEY_N_LEN];
-    let pub_key_index = SIGNATURE_LEN + COMMON_HEADER;
-    signature.copy_from_slice(&witness[0..SIGNATURE_LEN]);
-    pub_key_e.copy_from_slice(&witness[pub_key_index..(pub_key_index + PUBLIC_KEY_E_LEN)]);
-    pub_key_n.copy_from_slice(&witness[(pub_key_index + PUBLIC_KEY_E_LEN)..]);
+    // The beneficiary leaves the witness lock empty
+    if lock_args.has_flag(args::FLAG_INHERITANCE) && witness_lock.as_ref().map_or(true, |lock| lock.is_empty()) {
+        return inheritance::verify_beneficiary(lock_args.payload(args::FLAG_INHERITANCE).ok_or(Error::InvalidArgument)?);
+    }
 
-    let pub_key_e = u32::from_le_bytes(pub_key_e);
+    let witness: Bytes = witness_lock.ok_or(Error::Encoding)?.unpack();
 
-    let pub_key_hash = compute_pub_key_hash(&pub_key_n, pub_key_e)?;
+    // In the unvaulting state a 65-byte witness lock is a secp256k1 signature of the recovery key
+    if let Some(vault) = lock_args.payload(args::FLAG_VAULT) {
+        if lock_args.has_flag(args::FLAG_UNVAULTING) && witness.len() == backup::SIGNATURE_LEN {
+            return vault::sweep(&lock_args, vault);
+        }
+    }
+
+    // A 65-byte witness lock is a secp256k1 signature of the backup key or of a session key
+    let secp256k1_signers = lock_args.has_flag(args::FLAG_BACKUP_KEY) || lock_args.has_flag(args::FLAG_DELEGATION);
+    if secp256k1_signers && witness.len() == backup::SIGNATURE_LEN {
+        let signer = backup::load_signer()?;
+        if lock_args.payload(args::FLAG_BACKUP_KEY).map_or(false, |backup_key_hash| signer[..] == backup_key_hash[..]) {
+            return check_spend_policies(&lock_args);
+        }
+        if !lock_args.has_flag(args::FLAG_DELEGATION) {
+            return Err(Error::WrongPubKey);
+        }
+        delegation::verify_session(&lock_args, &signer)?;
+        return check_spend_policies(&lock_args);
+    }
 
-    if args[..] != pub_key_hash {
-        return Err(Error::WrongPubKey);
+    // A RecoveryWitness requests or finalizes a guardian recovery
+    if lock_args.has_flag(args::FLAG_GUARDIANS) && RecoveryWitnessReader::verify(&witness, false).is_ok() {
+        return recovery::verify_recovery(&lock_args, &witness, lock_args.payload(args::FLAG_GUARDIANS).ok_or(Error::InvalidArgument)?);
     }
-    
-    let message = generate_message()?;
 
+    // With a whitelist the passport wraps its witness lock with the proofs of the outputs
+    let lock = match lock_args.payload(args::FLAG_WHITELIST) {
+        Some(root) => whitelist::check_outputs(&witness, root)?,
+        None => &witness[..],
+    };
+
+    // With a revocation registry the passport also wraps its witness lock with the positions
+    // of its signers in the registry
+    let (revocation_positions, lock) = match lock_args.payload(args::FLAG_REVOCATION) {
+        Some(_) => revocation::unwrap_witness(lock)?,
+        None => (Vec::new(), lock),
+    };
+
+    // A passport which reveals its document wraps its witness lock with DG1 and the security
+    // object of its EF.SOD
+    let (document, lock) = if lock_args.has_flag(args::FLAG_DOCUMENT_REVEAL) {
+        let (document, lock) = document::unwrap_witness(lock)?;
+        (Some(document), lock)
+    } else {
+        (None, lock)
+    };
+
+    // Multisig signers always sign the whole transaction
+    let mut registry_data = Vec::new();
+    let (auths, sighash_type) = if lock_args.has_flag(args::FLAG_MULTISIG) {
+        (multisig::check_signers(lock, lock_args.pub_key_hash)?, sighash::SIGHASH_ALL)
+    } else {
+        let auth = check_auth(parse_witness_lock(lock, &mut registry_data)?)?;
+        if lock_args.pub_key_hash != auth.pub_key_hash {
+            // Only the successor designated in the grace mode of a rotation claims the cells
+            let grace = lock_args.payload(args::FLAG_ROTATION_GRACE).ok_or(Error::WrongPubKey)?;
+            rotation::verify_grace_claim(&lock_args, &auth.pub_key_hash, grace)?;
+        }
+        let sighash_type = auth.lock.sighash_type;
+        (vec![auth], sighash_type)
+    };
+
+    // No signer may digest with a weaker md_type than the minimum of the args
+    if let Some(min_digest) = lock_args.payload(args::FLAG_MIN_DIGEST) {
+        digest::check_floor(min_digest[0], &auths)?;
+    }
+
+    // No signer may be listed in the revocation registry
+    if let Some(registry_type_hash) = lock_args.payload(args::FLAG_REVOCATION) {
+        let pub_key_hashes: Vec<&[u8]> = auths.iter().map(|auth| &auth.pub_key_hash[..]).collect();
+        revocation::check_not_revoked(registry_type_hash, &revocation_positions, &pub_key_hashes)?;
+    }
+
+    // Every signer must be a passport which passed passive authentication
+    if let Some(registration_type_hash) = lock_args.payload(args::FLAG_PASSIVE_AUTH) {
+        let pub_key_hashes: Vec<&[u8]> = auths.iter().map(|auth| &auth.pub_key_hash[..]).collect();
+        passive_auth::check_registered(registration_type_hash, &pub_key_hashes)?;
+    }
+
+    // The passport reveals the document the args commit to
+    if let Some(document) = document {
+        document::check_reveal(&lock_args, &auths[0].pub_key_hash, document)?;
+    }
+
+    check_spend_policies(&lock_args)?;
+
+    let message = generate_message(sighash_type, witness.len())?;
+    verify_auths(&auths, &message)
+}
+
+/// Checks the policies of the args on what the passport, or a session key, may spend
+fn check_spend_policies(lock_args: &args::LockArgs) -> Result<(), Error> {
+    // A vault only lets the passport unvault, or spend after the unvaulting delay
+    if let Some(vault) = lock_args.payload(args::FLAG_VAULT) {
+        vault::check_passport_spend(lock_args, vault)?;
+    }
+
+    // A signed transaction moves at most the spending limit out of the lock
+    if let Some(limit) = lock_args.payload(args::FLAG_TX_SPENDING_LIMIT) {
+        limit::check_spending_limit(limit)?;
+    }
+
+    // Signed transactions move at most the epoch spending limit out of the lock within an epoch
+    if let Some(limit) = lock_args.payload(args::FLAG_EPOCH_SPENDING_LIMIT) {
+        limit::check_epoch_spending_limit(limit)?;
+    }
+    Ok(())
+}
+
+/// Verifies the signatures of every signer over the message
+fn verify_auths(auths: &[Auth], message: &[u8; MESSAGE_SIZE]) -> Result<(), Error> {
     let mut context = unsafe { CKBDLContext::<[u8; 1024 * 128]>::new() };
-    let lib = ckb_lib_rsa::LibRSA::load(&mut context);
-
-    for index in 0..4 {
-        let sub_message = &message[MESSAGE_SINGLE_SIZE * index..MESSAGE_SINGLE_SIZE * (index + 1)];
-        let sub_signature = &signature[SUB_SIGNATURE_LEN * index..SUB_SIGNATURE_LEN * (index + 1)];
-        match rsa::verify_iso9796_2_signature(&lib, &pub_key_n, pub_key_e, sub_message, sub_signature) {
-            Ok(_) => continue,
-            Err(err) => return Err(err)
+    let lib = if auths.iter().any(|auth| !auth.lock.ecdsa) {
+        Some(ckb_lib_rsa::LibRSA::load(&mut context))
+    } else {
+        None
+    };
+
+    for auth in auths.iter() {
+        match &lib {
+            Some(lib) if !auth.lock.ecdsa => verify_rsa_auth(auth, message, lib)?,
+            _ => verify_ecdsa_auth(auth, message)?,
         }
     }
+    Ok(())
+}
+
+/// Checks the header and the field lengths of a witness lock and computes its pubkey hash
+fn check_auth(lock: WitnessLock) -> Result<Auth, Error> {
+    let header = lock.header;
+    let algorithm_id = header[0];
+    let key_size = header[1];
+    let padding = header[2] & PADDING_MASK;
+    let chunking = header[2] >> CHUNKING_SHIFT;
+    let md_type = header[3];
+    digest::check_md_type(md_type)?;
+    let chunk_size = get_chunk_size(algorithm_id, chunking)?;
+    let signature_count = lock.signatures.len();
 
+    // The key header is what the verifiers understand, the committed header adds the chunking
+    let (key_header, pub_key_len, sub_signature_len) = if lock.ecdsa {
+        if algorithm_id != ecdsa::ECDSA_ALGORITHM_ID {
+            return Err(Error::UnsupportedAlgorithm);
+        }
+        ecdsa::check_padding(padding)?;
+        let field_len = ecdsa::get_field_len(key_size)?;
+        if signature_count * chunk_size != MESSAGE_SIZE {
+            return Err(Error::ECDSAPubKeySigLengthError);
+        }
+        (ecdsa::get_common_header(key_size, md_type), 1 + field_len, field_len * 2)
+    } else {
+        rsa::check_padding(algorithm_id, padding)?;
+        let n_len = rsa::get_key_len(key_size)?;
+        if signature_count * chunk_size != MESSAGE_SIZE {
+            return Err(Error::RSAPubKeySigLengthError);
+        }
+        (rsa::get_common_header(algorithm_id, key_size, padding, md_type), PUBLIC_KEY_E_LEN + n_len, n_len)
+    };
+    let mut common_header = key_header;
+    common_header[2] |= chunking << CHUNKING_SHIFT;
+    if header != common_header {
+        return Err(Error::UnsupportedHeader);
+    }
+
+    check_field_len(lock.pub_key.len(), pub_key_len)?;
+    for signature in lock.signatures.iter() {
+        check_field_len(signature.len(), sub_signature_len)?;
+    }
+
+    let pub_key_hash = compute_pub_key_hash(&common_header, lock.pub_key);
+    Ok(Auth { lock, key_header, chunk_size, pub_key_hash })
+}
+
+fn verify_ecdsa_auth(auth: &Auth, message: &[u8; MESSAGE_SIZE]) -> Result<(), Error> {
+    let md_type = auth.key_header[3];
+    let pub_key = ecdsa::load_pub_key(auth.key_header[1], auth.lock.pub_key)?;
+    for (sub_message, sub_signature) in message.chunks(auth.chunk_size).zip(auth.lock.signatures.iter()) {
+        ecdsa::verify_ecdsa_signature(&pub_key, md_type, sub_message, sub_signature)?;
+    }
+    Ok(())
+}
+
+fn verify_rsa_auth(auth: &Auth, message: &[u8; MESSAGE_SIZE], lib: &ckb_lib_rsa::LibRSA) -> Result<(), Error> {
+    let pub_key = auth.lock.pub_key;
+    let mut pub_key_e = [0u8; PUBLIC_KEY_E_LEN];
+    pub_key_e.copy_from_slice(&pub_key[0..PUBLIC_KEY_E_LEN]);
+    let pub_key_e = u32::from_le_bytes(pub_key_e);
+    let pub_key_n = &pub_key[PUBLIC_KEY_E_LEN..];
+
+    for (sub_message, sub_signature) in message.chunks(auth.chunk_size).zip(auth.lock.signatures.iter()) {
+        rsa::verify_rsa_signature(lib, &auth.key_header, pub_key_n, pub_key_e, sub_message, sub_signature)?;
+    }
+    Ok(())
+}
+
+/// Reads a versioned PassportLockWitness, a witness lock which is not one is parsed as the
+/// legacy signatures || common header || public key layout, where every signature has the
+/// same length. The public key is E || N for RSA and the compressed point for ECDSA.
+///
+/// A PassportLockWitnessV3 leaves the common header and the public key to the data of a
+/// registry cell dep, which are loaded into registry_data. The data need no other check, the
+/// pubkey hash computed from them must match the args as for a key in the witness.
+fn parse_witness_lock<'a>(witness: &'a [u8], registry_data: &'a mut Vec<u8>) -> Result<WitnessLock<'a>, Error> {
+    if let Ok(reader) = PassportLockWitnessV3Reader::from_slice(witness) {
+        if reader.version().as_slice()[0] == WITNESS_VERSION_REGISTRY {
+            let mut index = [0u8; 4];
+            index.copy_from_slice(reader.pubkey_dep().raw_data());
+            *registry_data = load_cell_data(u32::from_le_bytes(index) as usize, Source::CellDep)?;
+            let registry_data: &'a Vec<u8> = registry_data;
+            if registry_data.len() < COMMON_HEADER {
+                return Err(Error::WitnessTooShort);
+            }
+            let (header, pub_key) = registry_data.split_at(COMMON_HEADER);
+            return Ok(WitnessLock {
+                ecdsa: header[0] == ecdsa::ECDSA_ALGORITHM_ID,
+                header,
+                pub_key,
+                signatures: read_signatures(reader.signatures()),
+                sighash_type: reader.sighash_type().as_slice()[0],
+            });
+        }
+    }
+
+    // A PassportLockWitnessV2 reads as a PassportLockWitness with an extra field
+    if let Ok(reader) = PassportLockWitnessReader::from_compatible_slice(witness) {
+        let lock = read_witness_lock(reader.header(), reader.pubkey(), reader.signatures());
+        return match reader.version().as_slice()[0] {
+            WITNESS_VERSION if reader.has_extra_fields() => Err(Error::WitnessTrailingBytes),
+            WITNESS_VERSION => Ok(lock),
+            WITNESS_VERSION_SIGHASH => {
+                let reader = PassportLockWitnessV2Reader::from_slice(witness).map_err(|_| Error::WitnessTrailingBytes)?;
+                Ok(WitnessLock { sighash_type: reader.sighash_type().as_slice()[0], ..lock })
+            }
+            _ => Err(Error::UnsupportedWitnessVersion),
+        };
+    }
+
+    let layout = find_key_layout(witness.len())?;
+    let signature_len = layout.sub_signature_len * layout.signature_count;
+    Ok(WitnessLock {
+        ecdsa: layout.ecdsa,
+        header: &witness[signature_len..(signature_len + COMMON_HEADER)],
+        pub_key: &witness[(signature_len + COMMON_HEADER)..],
+        signatures: witness[0..signature_len].chunks(layout.sub_signature_len).collect(),
+        sighash_type: sighash::SIGHASH_ALL,
+    })
+}
+
+/// Reads the fields of a PassportLockWitness or of a multisig PassportSigner
+fn read_witness_lock<'a>(
+    header: AuthHeaderReader<'a>,
+    pub_key: BytesReader<'a>,
+    signatures: BytesVecReader<'a>,
+) -> WitnessLock<'a> {
+    let header = header.raw_data();
+    WitnessLock {
+        ecdsa: header[0] == ecdsa::ECDSA_ALGORITHM_ID,
+        header,
+        pub_key: pub_key.raw_data(),
+        signatures: read_signatures(signatures),
+        sighash_type: sighash::SIGHASH_ALL,
+    }
+}
+
+fn read_signatures(signatures: BytesVecReader) -> Vec<&[u8]> {
+    (0..signatures.len())
+        .filter_map(|i| signatures.get(i))
+        .map(|signature| signature.raw_data())
+        .collect()
+}
+
+/// Finds the key layout from the witness lock length. Every combination of RSA key size or
+/// ECDSA field size with a signature count gives a distinct length.
+fn find_key_layout(witness_len: usize) -> Result<KeyLayout, Error> {
+    let mut min_len = usize::MAX;
+    for signature_count in SIGNATURE_COUNTS.iter() {
+        for (_, n_len) in rsa::KEY_SIZES.iter() {
+            let total_len = signature_total_len(*n_len, *signature_count, PUBLIC_KEY_E_LEN + *n_len);
+            if witness_len == total_len {
+                return Ok(KeyLayout { ecdsa: false, sub_signature_len: *n_len, signature_count: *signature_count });
+            }
+            min_len = core::cmp::min(min_len, total_len);
+        }
+        for curve_id in ecdsa::CURVES.iter() {
+            let field_len = ecdsa::get_field_len(*curve_id)?;
+            let total_len = signature_total_len(field_len * 2, *signature_count, 1 + field_len);
+            if witness_len == total_len {
+                return Ok(KeyLayout { ecdsa: true, sub_signature_len: field_len * 2, signature_count: *signature_count });
+            }
+            min_len = core::cmp::min(min_len, total_len);
+        }
+    }
+    if witness_len < min_len {
+        return Err(Error::WitnessTooShort);
+    }
+    Err(Error::UnsupportedKeySize)
+}
+
+/// Checks the length of a public key or signature against the one its header requires
+fn check_field_len(len: usize, expected_len: usize) -> Result<(), Error> {
+    if len < expected_len {
+        return Err(Error::WitnessTooShort);
+    }
+    if len > expected_len {
+        return Err(Error::WitnessTrailingBytes);
+    }
     Ok(())
 }
 
-fn generate_message() -> Result<[u8; 32], Error> {
+/// Returns the length in bytes of every signed chunk of the message. The default keeps four
+/// 8-byte AA challenges for ISO 9796-2 and ECDSA and the whole message for PKCS#1.
+fn get_chunk_size(algorithm_id: u8, chunking: u8) -> Result<usize, Error> {
+    match chunking {
+        CHUNKING_DEFAULT if algorithm_id == rsa::RSA_ALGORITHM_ID => Ok(32),
+        CHUNKING_DEFAULT | CHUNKING_8 => Ok(8),
+        CHUNKING_16 => Ok(16),
+        CHUNKING_32 => Ok(32),
+        _ => Err(Error::UnsupportedChunkSize),
+    }
+}
+
+fn signature_total_len(sub_signature_len: usize, signature_count: usize, pub_key_len: usize) -> usize {
+    sub_signature_len * signature_count + COMMON_HEADER + pub_key_len
+}
+
+fn generate_message(sighash_type: u8, signature_total_len: usize) -> Result<[u8; MESSAGE_SIZE], Error> {
     let witness_args = load_witness_args(0, Source::GroupInput)?;
-    let tx_hash = load_tx_hash()?;
     let mut blake2b = hash::new_blake2b();
     let mut message = [0u8; 32];
-    blake2b.update(&tx_hash);
+    if sighash_type == sighash::SIGHASH_ALL {
+        blake2b.update(&load_tx_hash()?);
+    } else {
+        sighash::digest_tx(&mut blake2b, sighash_type)?;
+    }
     let zero_lock: Bytes = {
         let mut buf = Vec::new();
-        buf.resize(SIGNATURE_TOTAL_LEN, 0);
+        buf.resize(signature_total_len, 0);
         buf.into()
     };
     let witness_for_digest = witness_args
@@ -91,32 +460,23 @@ fn generate_message() -> Result<[u8; 32], Error> {
 
     // Digest same group witnesses
     let mut i = 1;
-    let mut witness_buf = Vec::new();
     loop {
-        match load_witness(&mut witness_buf, 0, i, Source::GroupInput) {
-            Ok(_witness) => {
-                let witness_len = witness_buf.len() as u64;
-                blake2b.update(&witness_len.to_le_bytes());
-                blake2b.update(&witness_buf);
-                i += 1;
-            },
+        match digest_witness(&mut blake2b, i, Source::GroupInput) {
+            Ok(()) => i += 1,
             Err(SysError::IndexOutOfBound) => break,
             Err(err) => return Err(err.into()),
         }
     }
 
-    // Digest witnesses that not covered by inputs
-    let mut i = load_transaction()?.raw().inputs().len();
-    loop {
-        match load_witness(&mut witness_buf, 0, i, Source::Input) {
-            Ok(_witness) => {
-                let witness_len = witness_buf.len() as u64;
-                blake2b.update(&witness_len.to_le_bytes());
-                blake2b.update(&witness_buf);
-                i += 1;
-            },
-            Err(SysError::IndexOutOfBound) => break,
-            Err(err) => return Err(err.into()),
+    // Digest witnesses that not covered by inputs, which only SIGHASH_ALL covers
+    if sighash_type == sighash::SIGHASH_ALL {
+        let mut i = load_transaction()?.raw().inputs().len();
+        loop {
+            match digest_witness(&mut blake2b, i, Source::Input) {
+                Ok(()) => i += 1,
+                Err(SysError::IndexOutOfBound) => break,
+                Err(err) => return Err(err.into()),
+            }
         }
     }
     blake2b.finalize(&mut message);
@@ -124,16 +484,36 @@ fn generate_message() -> Result<[u8; 32], Error> {
     Ok(message)
 }
 
-fn compute_pub_key_hash(pub_key_n: &[u8], pub_key_e: u32) -> Result<[u8; 20], Error> {
-    let pub_key_vec_len = COMMON_HEADER + PUBLIC_KEY_N_LEN + PUBLIC_KEY_E_LEN; // common_header + n.len + e.len
-    let mut pub_key_vec = Vec::new();
-    for _ in 0..pub_key_vec_len {
-        pub_key_vec.push(0u8);
+/// Digests the length and the content of a witness, witnesses longer than the buffer are
+/// loaded and digested chunk by chunk
+fn digest_witness(blake2b: &mut hash::Blake2b, index: usize, source: Source) -> Result<(), SysError> {
+    let mut buf = [0u8; WITNESS_BUF_LEN];
+    let witness_len = match load_witness(&mut buf, 0, index, source) {
+        Ok(len) => len,
+        Err(SysError::LengthNotEnough(len)) => len,
+        Err(err) => return Err(err),
+    };
+    blake2b.update(&(witness_len as u64).to_le_bytes());
+
+    let mut offset = 0;
+    while offset < witness_len {
+        if offset > 0 {
+            match load_witness(&mut buf, offset, index, source) {
+                Ok(_) | Err(SysError::LengthNotEnough(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        let chunk_len = core::cmp::min(WITNESS_BUF_LEN, witness_len - offset);
+        blake2b.update(&buf[..chunk_len]);
+        offset += chunk_len;
     }
+    Ok(())
+}
 
-    pub_key_vec[0..4].copy_from_slice(&rsa::get_common_header());
-    pub_key_vec[4..8].copy_from_slice(&pub_key_e.to_le_bytes());
-    pub_key_vec[8..].copy_from_slice(&pub_key_n);
+fn compute_pub_key_hash(common_header: &[u8; COMMON_HEADER], pub_key: &[u8]) -> [u8; 20] {
+    let mut pub_key_vec = Vec::with_capacity(COMMON_HEADER + pub_key.len());
+    pub_key_vec.extend_from_slice(common_header);
+    pub_key_vec.extend_from_slice(pub_key);
 
-    Ok(hash::blake2b_160(pub_key_vec))
-}
\ No newline at end of file
+    hash::blake2b_160(pub_key_vec)
+}
@@ -0,0 +1,96 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_std::{
+  ckb_constants::Source,
+  error::SysError,
+  high_level::{load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_script_hash, QueryIter},
+  syscalls::load_witness,
+};
+use crate::error::Error;
+
+const UDT_AMOUNT_LEN: usize = 16;
+
+/// Capacity, type, UDT amount and data of a cell
+struct Balance {
+  type_hash: Option<[u8; 32]>,
+  capacity: u64,
+  amount: u128,
+  data: Vec<u8>,
+}
+
+/// Tells whether the group has no witness or an empty one, which is an anyone-can-pay transfer
+pub fn is_unsigned() -> Result<bool, Error> {
+  match load_witness(&mut [], 0, 0, Source::GroupInput) {
+    Ok(_) | Err(SysError::IndexOutOfBound) => Ok(true),
+    Err(SysError::LengthNotEnough(_)) => Ok(false),
+    Err(err) => Err(err.into()),
+  }
+}
+
+/// Anyone may spend the cells of the group without the passport as long as every one of
+/// them is paid: an output of this same lock and type gets at least its capacity and UDT
+/// amount back, plus the minimum of CKB or of UDT. Every type may appear at most once in the
+/// group and in the outputs of this lock, so that payments are not merged. The output keeps
+/// the data of the input but the UDT amount, as other unlock paths read it as a state.
+pub fn verify_payment(payload: &[u8]) -> Result<(), Error> {
+  let ckb_minimum = minimum(payload[0])?;
+  let udt_minimum = minimum(payload[1])?;
+
+  let inputs = (0..QueryIter::new(load_cell_capacity, Source::GroupInput).count())
+    .map(|index| load_balance(index, Source::GroupInput))
+    .collect::<Result<Vec<Balance>, Error>>()?;
+  let script_hash = load_script_hash()?;
+  let outputs = QueryIter::new(load_cell_lock_hash, Source::Output)
+    .enumerate()
+    .filter(|(_, lock_hash)| *lock_hash == script_hash)
+    .map(|(index, _)| load_balance(index, Source::Output))
+    .collect::<Result<Vec<Balance>, Error>>()?;
+
+  for input in inputs.iter() {
+    if inputs.iter().filter(|other| other.type_hash == input.type_hash).count() > 1 {
+      return Err(Error::PaymentOutputMismatch);
+    }
+    let mut matches = outputs.iter().filter(|output| output.type_hash == input.type_hash);
+    let output = matches.next().ok_or(Error::PaymentOutputMismatch)?;
+    if matches.next().is_some() {
+      return Err(Error::PaymentOutputMismatch);
+    }
+
+    let state_offset = if input.type_hash.is_some() { UDT_AMOUNT_LEN } else { 0 };
+    if output.data[state_offset..] != input.data[state_offset..] {
+      return Err(Error::PaymentOutputMismatch);
+    }
+
+    if output.capacity < input.capacity || output.amount < input.amount {
+      return Err(Error::PaymentTooSmall);
+    }
+    let ckb_paid = (output.capacity - input.capacity) as u128 >= ckb_minimum;
+    let udt_paid = input.type_hash.is_some() && output.amount - input.amount >= udt_minimum;
+    if !ckb_paid && !udt_paid {
+      return Err(Error::PaymentTooSmall);
+    }
+  }
+  Ok(())
+}
+
+fn minimum(exponent: u8) -> Result<u128, Error> {
+  10u128.checked_pow(exponent as u32).ok_or(Error::InvalidArgument)
+}
+
+/// The UDT amount is the first 16 bytes of the data of a typed cell
+fn load_balance(index: usize, source: Source) -> Result<Balance, Error> {
+  let capacity = load_cell_capacity(index, source)?;
+  let type_hash = load_cell_type_hash(index, source)?;
+  let data = load_cell_data(index, source)?;
+  let amount = if type_hash.is_some() {
+    if data.len() < UDT_AMOUNT_LEN {
+      return Err(Error::Encoding);
+    }
+    let mut amount = [0u8; UDT_AMOUNT_LEN];
+    amount.copy_from_slice(&data[..UDT_AMOUNT_LEN]);
+    u128::from_le_bytes(amount)
+  } else {
+    0
+  };
+  Ok(Balance { type_hash, capacity, amount, data })
+}
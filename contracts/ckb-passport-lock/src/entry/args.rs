@@ -0,0 +1,77 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use crate::error::Error;
+use ckb_passport_lock_types::args::{self as layout, ArgsError};
+pub use ckb_passport_lock_types::args::{
+  FLAG_ACP, FLAG_BACKUP_KEY, FLAG_DELEGATION, FLAG_DOCUMENT, FLAG_DOCUMENT_REVEAL, FLAG_EPOCH_SPENDING_LIMIT,
+  FLAG_GRACE_CLAIMED, FLAG_GUARDIANS, FLAG_INHERITANCE, FLAG_MIN_DIGEST, FLAG_MULTISIG, FLAG_PASSIVE_AUTH,
+  FLAG_RECOVERY_PENDING, FLAG_REVOCATION, FLAG_ROTATION_GRACE, FLAG_SINCE, FLAG_TX_SPENDING_LIMIT, FLAG_UNVAULTING,
+  FLAG_VAULT, FLAG_WHITELIST, PUB_KEY_HASH_LEN,
+};
+
+/// The args are the 20-byte pubkey hash, optionally followed by flags bytes and the payloads
+/// of the set flags. Plain 20-byte args have no flag set. The flags, their payloads and the
+/// rules on their combination are those of ckb-passport-lock-types, which the SDK shares.
+pub struct LockArgs<'a> {
+  pub pub_key_hash: &'a [u8],
+  pub flags: u32,
+  payloads: &'a [u8],
+}
+
+impl<'a> LockArgs<'a> {
+  pub fn has_flag(&self, flag: u32) -> bool {
+    self.flags & flag != 0
+  }
+
+  /// Returns a u64 little endian payload
+  pub fn payload_u64(&self, flag: u32) -> Option<u64> {
+    self.payload(flag).map(|payload| {
+      let mut value = [0u8; 8];
+      value.copy_from_slice(&payload[..8]);
+      u64::from_le_bytes(value)
+    })
+  }
+
+  /// Returns the payload of a set flag
+  pub fn payload(&self, flag: u32) -> Option<&'a [u8]> {
+    if self.flags & flag == 0 {
+      return None;
+    }
+    let offset = layout::payload_offset(self.flags, flag);
+    Some(&self.payloads[offset..(offset + layout::payload_len(flag)?)])
+  }
+
+  /// Encodes the args with other flags, which may only differ by flags without payload
+  pub fn with_flags(&self, flags: u32) -> Vec<u8> {
+    let mut args = self.pub_key_hash.to_vec();
+    layout::encode_flags(&mut args, flags);
+    args.extend_from_slice(self.payloads);
+    args
+  }
+
+  /// Encodes the args with another flag set, whose payload goes at the position of the flag
+  pub fn with_payload(&self, flag: u32, payload: &[u8]) -> Vec<u8> {
+    let mut args = self.with_flags(self.flags | flag);
+    let offset = args.len() - self.payloads.len() + layout::payload_offset(self.flags, flag);
+    args.splice(offset..offset, payload.iter().cloned());
+    args
+  }
+}
+
+pub fn parse_args(args: &[u8]) -> Result<LockArgs, Error> {
+  let (flags, offset) = layout::parse_flags(args)?;
+  Ok(LockArgs {
+    pub_key_hash: &args[..PUB_KEY_HASH_LEN],
+    flags,
+    payloads: &args[offset..],
+  })
+}
+
+impl From<ArgsError> for Error {
+  fn from(err: ArgsError) -> Self {
+    match err {
+      ArgsError::Invalid => Self::InvalidArgument,
+      ArgsError::UnsupportedFlags => Self::UnsupportedArgsFlags,
+    }
+  }
+}
@@ -0,0 +1,28 @@
+use core::result::Result;
+use ckb_std::dynamic_loading::CKBDLContext;
+use crate::error::Error;
+
+/// Recoverable secp256k1 signature, as in the default secp256k1_blake160_sighash_all lock
+pub const SIGNATURE_LEN: usize = 65;
+
+/// Unlocks with the backup key when the passport is lost or expired. The witness lock is a
+/// sighash-all secp256k1 signature whose signer's blake160 must match the args payload.
+pub fn verify_backup_key(backup_key_hash: &[u8]) -> Result<(), Error> {
+  if load_signer()?[..] != backup_key_hash[..] {
+    return Err(Error::WrongPubKey);
+  }
+  Ok(())
+}
+
+/// Verifies the sighash-all secp256k1 signature of the witness lock and returns the blake160
+/// of its signer
+pub fn load_signer() -> Result<[u8; 20], Error> {
+  let mut context = unsafe { CKBDLContext::<[u8; 1024 * 128]>::new() };
+  let lib = ckb_lib_secp256k1::LibSecp256k1::load(&mut context);
+
+  let mut pub_key_hash = [0u8; 20];
+  lib
+    .validate_blake2b_sighash_all(&mut pub_key_hash)
+    .map_err(|_| Error::Secp256k1VerifyError)?;
+  Ok(pub_key_hash)
+}
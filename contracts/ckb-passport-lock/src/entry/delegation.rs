@@ -0,0 +1,144 @@
+use core::result::Result;
+use alloc::{vec, vec::Vec};
+use ckb_std::{
+  ckb_constants::Source,
+  ckb_types::{packed::Script, prelude::*},
+  high_level::{load_cell_data, load_cell_lock_hash, load_cell_type, load_cell_type_hash, load_script_hash, QueryIter},
+};
+use ckb_passport_lock_types::{DelegationCellReader, Uint64Reader};
+use crate::error::Error;
+use super::{
+  args::{self, LockArgs},
+  check_auth, digest, hash, limit, multisig, parse_witness_lock, passive_auth, since, verify_auths,
+};
+
+/// Code hash and hash type of the type id script built into CKB
+const TYPE_ID_CODE_HASH: [u8; 32] = [
+  0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, b'T', b'Y', b'P', b'E', b'_', b'I', b'D',
+];
+const TYPE_ID_HASH_TYPE: u8 = 1;
+
+/// A delegation cell is a cell of this lock with a type id, whose data are a DelegationCell:
+/// the certificate of a secp256k1 session key, with its expiry and an optional cap on the net
+/// outflow of a transaction, and a witness lock of the passport signing the blake2b of the
+/// certificate. The certificate commits to the type id, so it is only valid in its own cell.
+///
+/// While the delegation cell is a cell dep, the session key unlocks the cells with a
+/// sighash-all secp256k1 signature instead of the passport. A session spend does not check
+/// the expiry, which since cannot bound from above: the delegation ends once its cell is
+/// consumed, by the passport revoking it or by anyone after the expiry, see expire.
+pub fn verify_session(lock_args: &LockArgs, signer: &[u8; 20]) -> Result<(), Error> {
+  let script_hash = load_script_hash()?;
+  let (data, type_hash) = find_delegation(&script_hash, signer)?;
+  let cell = DelegationCellReader::new_unchecked(&data);
+  let certificate = cell.certificate();
+
+  // The session key may not move a delegation cell, which would keep its type id alive
+  if QueryIter::new(load_cell_type_hash, Source::GroupInput).any(|input_type_hash| input_type_hash == Some(type_hash)) {
+    return Err(Error::DelegationCellSpent);
+  }
+
+  // The passport signs the certificate as it signs a transaction
+  let mut registry_data = Vec::new();
+  let passport_lock = cell.passport_lock().raw_data();
+  let auths = if lock_args.has_flag(args::FLAG_MULTISIG) {
+    multisig::check_signers(passport_lock, lock_args.pub_key_hash)?
+  } else {
+    let auth = check_auth(parse_witness_lock(passport_lock, &mut registry_data)?)?;
+    if lock_args.pub_key_hash != auth.pub_key_hash {
+      return Err(Error::WrongPubKey);
+    }
+    vec![auth]
+  };
+  if let Some(min_digest) = lock_args.payload(args::FLAG_MIN_DIGEST) {
+    digest::check_floor(min_digest[0], &auths)?;
+  }
+  if let Some(registration_type_hash) = lock_args.payload(args::FLAG_PASSIVE_AUTH) {
+    let pub_key_hashes: Vec<&[u8]> = auths.iter().map(|auth| &auth.pub_key_hash[..]).collect();
+    passive_auth::check_registered(registration_type_hash, &pub_key_hashes)?;
+  }
+  verify_auths(&auths, &hash::blake2b_256(certificate.as_slice()))?;
+
+  if let Some(spend_cap) = certificate.spend_cap().to_opt() {
+    if limit::outflow(&script_hash) > read_u64(spend_cap) {
+      return Err(Error::DelegationCapExceeded);
+    }
+  }
+  Ok(())
+}
+
+/// Tells whether every input of the group is a delegation cell
+pub fn is_delegation_group() -> Result<bool, Error> {
+  for (index, data) in QueryIter::new(load_cell_data, Source::GroupInput).enumerate() {
+    let type_id = load_cell_type(index, Source::GroupInput)?.map_or(false, |type_script| is_type_id(&type_script));
+    if !type_id || DelegationCellReader::verify(&data, false).is_err() {
+      return Ok(false);
+    }
+  }
+  Ok(true)
+}
+
+/// Since only tells the earliest time of a transaction, the expiry ends a delegation through
+/// its cell: once the absolute since of the expiry is reached, anyone may consume the
+/// delegation cell without a signature. Its type id may not be re-created and its capacity
+/// goes back to cells of this lock.
+pub fn expire() -> Result<(), Error> {
+  let mut type_hashes = Vec::new();
+  for (index, data) in QueryIter::new(load_cell_data, Source::GroupInput).enumerate() {
+    let cell = DelegationCellReader::from_slice(&data).map_err(|_| Error::Encoding)?;
+    since::check_group_since(read_expiry(cell.certificate().expiry())?)?;
+    type_hashes.push(load_cell_type_hash(index, Source::GroupInput)?);
+  }
+
+  if QueryIter::new(load_cell_type_hash, Source::Output).any(|type_hash| type_hashes.contains(&type_hash)) {
+    return Err(Error::DelegationOutputMismatch);
+  }
+  if limit::outflow(&load_script_hash()?) > 0 {
+    return Err(Error::DelegationOutputMismatch);
+  }
+  Ok(())
+}
+
+/// Finds the delegation cell of the session key among the cell deps, returns its data and its
+/// type hash
+fn find_delegation(script_hash: &[u8; 32], signer: &[u8; 20]) -> Result<(Vec<u8>, [u8; 32]), Error> {
+  for (index, lock_hash) in QueryIter::new(load_cell_lock_hash, Source::CellDep).enumerate() {
+    if lock_hash != *script_hash {
+      continue;
+    }
+    let type_script = match load_cell_type(index, Source::CellDep)? {
+      Some(type_script) if is_type_id(&type_script) => type_script,
+      _ => continue,
+    };
+    let data = load_cell_data(index, Source::CellDep)?;
+    let matches = DelegationCellReader::from_slice(&data).map_or(false, |cell| {
+      let certificate = cell.certificate();
+      certificate.session_pubkey_hash().raw_data() == &signer[..]
+        && certificate.type_id().raw_data() == &type_script.args().raw_data()[..]
+    });
+    if matches {
+      let type_hash = load_cell_type_hash(index, Source::CellDep)?.ok_or(Error::ItemMissing)?;
+      return Ok((data, type_hash));
+    }
+  }
+  Err(Error::DelegationMissing)
+}
+
+fn is_type_id(script: &Script) -> bool {
+  script.code_hash().as_slice() == &TYPE_ID_CODE_HASH[..] && script.hash_type().as_slice()[0] == TYPE_ID_HASH_TYPE
+}
+
+/// The expiry is an absolute since
+fn read_expiry(expiry: Uint64Reader) -> Result<u64, Error> {
+  let expiry = read_u64(expiry);
+  if expiry & since::SINCE_RELATIVE_FLAG != 0 {
+    return Err(Error::InvalidArgument);
+  }
+  Ok(expiry)
+}
+
+fn read_u64(value: Uint64Reader) -> u64 {
+  let mut bytes = [0u8; 8];
+  bytes.copy_from_slice(value.raw_data());
+  u64::from_le_bytes(bytes)
+}
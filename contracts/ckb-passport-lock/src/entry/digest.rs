@@ -0,0 +1,30 @@
+use core::result::Result;
+use crate::error::Error;
+use super::Auth;
+
+// md_type ids of the common header, which follow mbedtls_md_type_t
+pub const MD_SHA1: u8 = 4;
+pub const MD_SHA224: u8 = 5;
+pub const MD_SHA256: u8 = 6;
+pub const MD_SHA384: u8 = 7;
+pub const MD_SHA512: u8 = 8;
+
+/// Checks that md_type is a SHA-1/SHA-2 digest
+pub fn check_md_type(md_type: u8) -> Result<(), Error> {
+  if md_type < MD_SHA1 || md_type > MD_SHA512 {
+    return Err(Error::UnsupportedDigest);
+  }
+  Ok(())
+}
+
+/// Checks that no signer digests with a weaker md_type than the minimum of the args, whatever
+/// the algorithm: ISO 9796-2, PKCS#1 and ECDSA alike
+pub(super) fn check_floor(min_md_type: u8, auths: &[Auth]) -> Result<(), Error> {
+  if min_md_type < MD_SHA1 || min_md_type > MD_SHA512 {
+    return Err(Error::InvalidArgument);
+  }
+  if auths.iter().any(|auth| auth.key_header[3] < min_md_type) {
+    return Err(Error::DigestTooWeak);
+  }
+  Ok(())
+}
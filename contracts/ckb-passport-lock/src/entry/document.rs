@@ -0,0 +1,106 @@
+use core::result::Result;
+use ckb_std::ckb_types::prelude::*;
+use ckb_passport_lock_types::DocumentWitnessReader;
+use crate::error::Error;
+use super::{
+  args::{self, LockArgs},
+  digest::{self, MD_SHA1, MD_SHA224, MD_SHA256, MD_SHA384, MD_SHA512},
+  hash, passive_auth,
+};
+
+const DG1: u8 = 1;
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+
+/// Digest algorithms of the LDSSecurityObject as (oid, md_type)
+const DIGESTS: [(&[u8], u8); 5] = [
+  (&[0x2B, 0x0E, 0x03, 0x02, 0x1A], MD_SHA1),
+  (&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x04], MD_SHA224),
+  (&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01], MD_SHA256),
+  (&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02], MD_SHA384),
+  (&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03], MD_SHA512),
+];
+
+/// The witness lock of a passport which reveals its document is a DocumentWitness, which
+/// wraps the witness lock of the signatures
+pub fn unwrap_witness(witness: &[u8]) -> Result<(DocumentWitnessReader, &[u8]), Error> {
+  let reader = DocumentWitnessReader::from_slice(witness).map_err(|_| Error::Encoding)?;
+  Ok((reader, reader.lock().raw_data()))
+}
+
+/// The revealed salt and DG1 must be the preimage of the commitment of the args, and the
+/// revealed LDSSecurityObject must hold the hash of DG1. The passport must be registered with
+/// the blake2b of that security object, so passive authentication verified the EF.SOD it comes
+/// from and its DG15 for the key of the passport.
+pub fn check_reveal(lock_args: &LockArgs, pub_key_hash: &[u8], witness: DocumentWitnessReader) -> Result<(), Error> {
+  let commitment = lock_args.payload(args::FLAG_DOCUMENT).ok_or(Error::InvalidArgument)?;
+  let registration_type_hash = lock_args.payload(args::FLAG_PASSIVE_AUTH).ok_or(Error::InvalidArgument)?;
+
+  let dg1 = witness.dg1().raw_data();
+  let mut blake2b = hash::new_blake2b();
+  blake2b.update(witness.salt().raw_data());
+  blake2b.update(dg1);
+  let mut dg1_commitment = [0u8; 32];
+  blake2b.finalize(&mut dg1_commitment);
+  if dg1_commitment[..] != commitment[..] {
+    return Err(Error::DocumentMismatch);
+  }
+
+  let security_object = witness.security_object().raw_data();
+  let (md_type, dg1_hash) = data_group_hash(security_object, DG1)?;
+  digest::check_md_type(md_type)?;
+  let (digest, digest_len) = ckb_lib_ecdsa::digest(md_type, dg1).ok_or(Error::UnsupportedDigest)?;
+  if dg1_hash != &digest[..digest_len] {
+    return Err(Error::DocumentMismatch);
+  }
+
+  passive_auth::check_registered_document(registration_type_hash, pub_key_hash, &hash::blake2b_256(security_object))
+}
+
+/// Returns the md_type of an LDSSecurityObject and the hash of a data group, the object is
+/// SEQUENCE { version, hashAlgorithm, SEQUENCE OF SEQUENCE { dataGroupNumber, hash } }
+fn data_group_hash(security_object: &[u8], number: u8) -> Result<(u8, &[u8]), Error> {
+  let (fields, _) = read_element(security_object, TAG_SEQUENCE)?;
+  let (_, fields) = read_element(fields, TAG_INTEGER)?;
+  let (algorithm, fields) = read_element(fields, TAG_SEQUENCE)?;
+  let (oid, _) = read_element(algorithm, TAG_OID)?;
+  let md_type = DIGESTS
+    .iter()
+    .find(|(entry, _)| *entry == oid)
+    .map(|(_, md_type)| *md_type)
+    .ok_or(Error::UnsupportedDigest)?;
+
+  let (mut hashes, _) = read_element(fields, TAG_SEQUENCE)?;
+  while !hashes.is_empty() {
+    let (hash, rest) = read_element(hashes, TAG_SEQUENCE)?;
+    hashes = rest;
+    let (group, hash) = read_element(hash, TAG_INTEGER)?;
+    let (value, _) = read_element(hash, TAG_OCTET_STRING)?;
+    if group == [number] {
+      return Ok((md_type, value));
+    }
+  }
+  Err(Error::DocumentMismatch)
+}
+
+/// Reads a DER element with a single byte tag and a definite length, returns its content and
+/// the data after it
+fn read_element(data: &[u8], tag: u8) -> Result<(&[u8], &[u8]), Error> {
+  if data.len() < 2 || data[0] != tag {
+    return Err(Error::Encoding);
+  }
+  let (len, header_len) = if data[1] & 0x80 == 0 {
+    (data[1] as usize, 2)
+  } else {
+    let count = (data[1] & 0x7F) as usize;
+    if count == 0 || count > 4 || data.len() < 2 + count {
+      return Err(Error::Encoding);
+    }
+    (data[2..(2 + count)].iter().fold(0usize, |len, byte| (len << 8) | *byte as usize), 2 + count)
+  };
+  let end = header_len.checked_add(len).filter(|end| *end <= data.len()).ok_or(Error::Encoding)?;
+  Ok((&data[header_len..end], &data[end..]))
+}
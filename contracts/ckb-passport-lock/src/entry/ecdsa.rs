@@ -0,0 +1,52 @@
+use core::result::Result;
+use ckb_lib_ecdsa::PublicKey;
+use crate::error::Error;
+
+pub fn verify_ecdsa_signature(pub_key: &PublicKey, md_type: u8, msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+  pub_key.verify(md_type, &msg, &sig).map_err(map_error)
+}
+
+/// The public key in the witness is the SEC1 compressed point 0x02/0x03 || X (FieldSize/8 bytes)
+/// and every signature is the plain r || s (FieldSize/8 bytes each) defined by BSI TR-03111,
+/// which is what the chip returns for Active Authentication.
+pub fn load_pub_key(curve_id: u8, pub_key: &[u8]) -> Result<PublicKey, Error> {
+  PublicKey::from_compressed(curve_id, &pub_key).map_err(map_error)
+}
+
+fn map_error(err: ckb_lib_ecdsa::Error) -> Error {
+  use ckb_lib_ecdsa::Error::*;
+  match err {
+    UnsupportedCurve => Error::UnsupportedCurve,
+    UnsupportedDigest => Error::UnsupportedDigest,
+    InvalidPubKey => Error::ECDSAInvalidPubKey,
+    InvalidSignature => Error::ECDSAInvalidSignature,
+    VerifyFailed => Error::ECDSAVerifyError,
+  }
+}
+
+/// Outside of the algorithm ids of validate_signature_rsa
+pub const ECDSA_ALGORITHM_ID: u8 = 16;
+const ECDSA_PADDING: u8 = 0;
+
+pub const CURVES: [u8; 4] = [
+  ckb_lib_ecdsa::CURVE_P256,
+  ckb_lib_ecdsa::CURVE_P384,
+  ckb_lib_ecdsa::CURVE_BRAINPOOL_P256R1,
+  ckb_lib_ecdsa::CURVE_BRAINPOOL_P384R1,
+];
+
+/// Returns the length in bytes of a field element for a curve id
+pub fn get_field_len(curve_id: u8) -> Result<usize, Error> {
+  ckb_lib_ecdsa::get_field_len(curve_id).ok_or(Error::UnsupportedCurve)
+}
+
+pub fn check_padding(padding: u8) -> Result<(), Error> {
+  if padding != ECDSA_PADDING {
+    return Err(Error::UnsupportedPadding);
+  }
+  Ok(())
+}
+
+pub fn get_common_header(curve_id: u8, md_type: u8) -> [u8; 4] {
+  [ECDSA_ALGORITHM_ID, curve_id, ECDSA_PADDING, md_type]
+}
@@ -0,0 +1,27 @@
+use core::result::Result;
+use ckb_std::{ckb_constants::Source, high_level::{load_cell_lock_hash, QueryIter}};
+use crate::error::Error;
+use super::since;
+
+const LOCK_HASH_LEN: usize = 32;
+
+/// The beneficiary unlocks the group once every input of it stayed unspent for the inactivity
+/// period. The beneficiary lock authorizes the transaction through one of its cells among the
+/// inputs. The passport resets the period by spending the cells back to itself.
+pub fn verify_beneficiary(payload: &[u8]) -> Result<(), Error> {
+  let beneficiary_lock_hash = &payload[..LOCK_HASH_LEN];
+  let mut period = [0u8; 8];
+  period.copy_from_slice(&payload[LOCK_HASH_LEN..]);
+  let period = u64::from_le_bytes(period);
+  if period & since::SINCE_RELATIVE_FLAG == 0 {
+    return Err(Error::InvalidArgument);
+  }
+  since::check_group_since(period)?;
+
+  let present = QueryIter::new(load_cell_lock_hash, Source::Input)
+    .any(|lock_hash| lock_hash[..] == beneficiary_lock_hash[..]);
+  if !present {
+    return Err(Error::BeneficiaryNotPresent);
+  }
+  Ok(())
+}
@@ -0,0 +1,121 @@
+use core::result::Result;
+use ckb_std::{
+  ckb_constants::{HeaderField, Source},
+  high_level::{load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash, load_input_since, load_script_hash, QueryIter},
+  syscalls::load_header_by_field,
+};
+use crate::error::Error;
+use super::{recovery, since};
+
+const SPENT_LEN: usize = 8;
+
+/// Caps what a signed transaction moves out of the lock: the capacity of the group minus the
+/// capacity of the outputs of this same lock. The cap holds per transaction only, the lock
+/// keeps no state across transactions, so several transactions within the limit are not
+/// capped together.
+///
+/// A larger transfer takes two steps. A first transaction within the limit re-creates the
+/// cells with the script hash of this lock as data, which marks them as pending. The pending
+/// cells may be spent beyond the limit once every one of them waited the delay, which gives
+/// the holder time to react to a stolen passport.
+pub fn check_spending_limit(payload: &[u8]) -> Result<(), Error> {
+  let limit = read_u64(&payload[..8]);
+  let delay = read_delay(&payload[8..16])?;
+
+  let script_hash = load_script_hash()?;
+  if outflow(&script_hash) <= limit {
+    return Ok(());
+  }
+  check_pending(&script_hash, delay)
+}
+
+/// Caps what the signed transactions of an epoch move out of the lock. A type id cell of this
+/// lock, whose type script hash the args commit to, keeps in its data what was spent (u64
+/// little endian) since the epoch of the block it was created in at the latest. A transaction
+/// moving capacity out of the lock spends that state cell and re-creates it with its outflow
+/// added. The state input starts a new count with an absolute epoch since beyond the epoch of
+/// its block, read from a header dep; no transaction reaches a since before its epoch, so the
+/// count restarts at most once an epoch.
+///
+/// Larger transfers take the two steps of the transaction limit: pending cells spent without
+/// the state cell may move beyond the limit once every one of them waited the delay.
+pub fn check_epoch_spending_limit(payload: &[u8]) -> Result<(), Error> {
+  let limit = read_u64(&payload[..8]);
+  let delay = read_delay(&payload[8..16])?;
+  let state_type_hash = &payload[16..];
+
+  let script_hash = load_script_hash()?;
+  let outflow = outflow(&script_hash);
+  let state_index = match find_state(&script_hash, state_type_hash, Source::Input)? {
+    Some(state_index) => state_index,
+    None if outflow == 0 => return Ok(()),
+    None => return check_pending(&script_hash, delay),
+  };
+
+  let mut epoch = [0u8; 8];
+  load_header_by_field(&mut epoch, 0, state_index, Source::Input, HeaderField::EpochNumber)?;
+  let new_epoch = since::absolute_epoch_number(load_input_since(state_index, Source::Input)?)
+    .map_or(false, |number| number > u64::from_le_bytes(epoch));
+  let spent = if new_epoch { 0 } else { read_spent(state_index, Source::Input)? };
+  let spent = spent
+    .checked_add(outflow)
+    .filter(|spent| *spent <= limit)
+    .ok_or(Error::SpendingLimitExceeded)?;
+
+  let output_index = find_state(&script_hash, state_type_hash, Source::Output)?.ok_or(Error::SpendingStateMismatch)?;
+  if read_spent(output_index, Source::Output)? != spent {
+    return Err(Error::SpendingStateMismatch);
+  }
+  Ok(())
+}
+
+/// Returns the capacity of the group minus the capacity of the outputs of this same lock
+pub fn outflow(script_hash: &[u8; 32]) -> u64 {
+  let group_capacity = QueryIter::new(load_cell_capacity, Source::GroupInput).sum::<u64>();
+  let returned_capacity = recovery::output_capacity(|lock_hash, _| lock_hash == script_hash);
+  group_capacity.saturating_sub(returned_capacity)
+}
+
+/// Pending cells, whose data is the script hash of this lock, may move beyond a limit once
+/// every one of them waited the delay
+fn check_pending(script_hash: &[u8; 32], delay: u64) -> Result<(), Error> {
+  let pending = QueryIter::new(load_cell_data, Source::GroupInput).all(|data| data[..] == script_hash[..]);
+  if !pending {
+    return Err(Error::SpendingLimitExceeded);
+  }
+  since::check_group_since(delay)
+}
+
+/// Finds the state cell, of this lock and of the type script hash of the args, in the source
+fn find_state(script_hash: &[u8; 32], state_type_hash: &[u8], source: Source) -> Result<Option<usize>, Error> {
+  for (index, type_hash) in QueryIter::new(load_cell_type_hash, source).enumerate() {
+    let matches = type_hash.map_or(false, |type_hash| type_hash[..] == state_type_hash[..]);
+    if matches && load_cell_lock_hash(index, source)? == *script_hash {
+      return Ok(Some(index));
+    }
+  }
+  Ok(None)
+}
+
+fn read_spent(index: usize, source: Source) -> Result<u64, Error> {
+  let data = load_cell_data(index, source)?;
+  if data.len() != SPENT_LEN {
+    return Err(Error::Encoding);
+  }
+  Ok(read_u64(&data))
+}
+
+/// The delay is a relative since
+fn read_delay(delay: &[u8]) -> Result<u64, Error> {
+  let delay = read_u64(delay);
+  if delay & since::SINCE_RELATIVE_FLAG == 0 {
+    return Err(Error::InvalidArgument);
+  }
+  Ok(delay)
+}
+
+fn read_u64(bytes: &[u8]) -> u64 {
+  let mut value = [0u8; 8];
+  value.copy_from_slice(bytes);
+  u64::from_le_bytes(value)
+}
@@ -0,0 +1,60 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_std::ckb_types::prelude::*;
+use ckb_passport_lock_types::{MultisigWitnessReader, WITNESS_VERSION};
+use crate::error::Error;
+use super::{args::PUB_KEY_HASH_LEN, check_auth, hash, read_witness_lock, Auth};
+
+/// The args of a multisig lock commit to blake160(threshold || count || pubkey hashes), where
+/// the pubkey hashes of the passports are sorted in ascending order. The witness lock is a
+/// MultisigWitness with at least threshold distinct signers of the message.
+pub(super) fn check_signers<'a>(witness: &'a [u8], multisig_hash: &[u8]) -> Result<Vec<Auth<'a>>, Error> {
+  let reader = MultisigWitnessReader::from_compatible_slice(witness).map_err(|_| Error::Encoding)?;
+  if reader.version().as_slice()[0] != WITNESS_VERSION {
+    return Err(Error::UnsupportedWitnessVersion);
+  }
+  if reader.has_extra_fields() {
+    return Err(Error::WitnessTrailingBytes);
+  }
+
+  let threshold = reader.threshold().as_slice()[0] as usize;
+  let pub_key_hashes = reader.pubkey_hashes();
+  let pub_key_hashes: Vec<&[u8]> = (0..pub_key_hashes.len())
+    .filter_map(|i| pub_key_hashes.get(i))
+    .map(|pub_key_hash| pub_key_hash.raw_data())
+    .collect();
+  let count = pub_key_hashes.len();
+  if threshold == 0 || threshold > count || count > u8::MAX as usize {
+    return Err(Error::InvalidMultisigConfig);
+  }
+  if pub_key_hashes.windows(2).any(|pair| pair[0] >= pair[1]) {
+    return Err(Error::InvalidMultisigConfig);
+  }
+
+  let mut config = Vec::with_capacity(2 + count * PUB_KEY_HASH_LEN);
+  config.push(threshold as u8);
+  config.push(count as u8);
+  for pub_key_hash in pub_key_hashes.iter() {
+    config.extend_from_slice(pub_key_hash);
+  }
+  if hash::blake2b_160(config)[..] != multisig_hash[..] {
+    return Err(Error::WrongPubKey);
+  }
+
+  let mut auths: Vec<Auth> = Vec::new();
+  let signers = reader.signers();
+  for signer in (0..signers.len()).filter_map(|i| signers.get(i)) {
+    let auth = check_auth(read_witness_lock(signer.header(), signer.pubkey(), signer.signatures()))?;
+    if pub_key_hashes.binary_search(&&auth.pub_key_hash[..]).is_err() {
+      return Err(Error::WrongPubKey);
+    }
+    if auths.iter().any(|other| other.pub_key_hash == auth.pub_key_hash) {
+      return Err(Error::DuplicateSigner);
+    }
+    auths.push(auth);
+  }
+  if auths.len() < threshold {
+    return Err(Error::MultisigThresholdNotMet);
+  }
+  Ok(auths)
+}
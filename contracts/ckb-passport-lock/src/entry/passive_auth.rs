@@ -0,0 +1,50 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_std::{
+  ckb_constants::Source,
+  high_level::{load_cell_data, load_cell_type_hash, QueryIter},
+};
+use crate::error::Error;
+
+const PUB_KEY_HASH_LEN: usize = 20;
+
+/// Every signer must have a registration cell among the cell deps, a cell with the type script
+/// hash of the args whose data start with its pubkey hash. The type script only creates those
+/// for passports whose EF.SOD verifies up to a CSCA of the master list.
+pub fn check_registered(registration_type_hash: &[u8], pub_key_hashes: &[&[u8]]) -> Result<(), Error> {
+  let registrations = load_registrations(registration_type_hash)?;
+  for pub_key_hash in pub_key_hashes {
+    if !registrations.iter().any(|data| data.get(..PUB_KEY_HASH_LEN) == Some(pub_key_hash)) {
+      return Err(Error::PassportNotRegistered);
+    }
+  }
+  Ok(())
+}
+
+/// A registration may also commit to the blake2b of the LDSSecurityObject of the passport
+/// after its pubkey hash, the signer must have such a registration for the security object
+pub fn check_registered_document(
+  registration_type_hash: &[u8],
+  pub_key_hash: &[u8],
+  security_object_hash: &[u8; 32],
+) -> Result<(), Error> {
+  let registrations = load_registrations(registration_type_hash)?;
+  let registered = registrations.iter().any(|data| {
+    data.len() == PUB_KEY_HASH_LEN + security_object_hash.len()
+      && data[..PUB_KEY_HASH_LEN] == pub_key_hash[..]
+      && data[PUB_KEY_HASH_LEN..] == security_object_hash[..]
+  });
+  if !registered {
+    return Err(Error::PassportNotRegistered);
+  }
+  Ok(())
+}
+
+fn load_registrations(registration_type_hash: &[u8]) -> Result<Vec<Vec<u8>>, Error> {
+  let registrations = QueryIter::new(load_cell_type_hash, Source::CellDep)
+    .enumerate()
+    .filter(|(_, type_hash)| type_hash.map_or(false, |type_hash| type_hash[..] == registration_type_hash[..]))
+    .map(|(index, _)| load_cell_data(index, Source::CellDep))
+    .collect::<Result<_, _>>()?;
+  Ok(registrations)
+}
@@ -0,0 +1,115 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_std::{
+  ckb_constants::Source,
+  ckb_types::{bytes::Bytes, prelude::*},
+  high_level::{load_cell_capacity, load_cell_lock_hash, load_script, load_script_hash, QueryIter},
+};
+use ckb_passport_lock_types::{RecoveryWitnessReader, WITNESS_VERSION};
+use crate::error::Error;
+use super::{args::{self, LockArgs}, hash, since};
+
+const GUARDIAN_HASH_LEN: usize = 20;
+const LOCK_HASH_LEN: usize = 32;
+
+/// Guardian recovery moves the cells of a lost passport to a new lock in two steps.
+///
+/// The request needs threshold guardians, found by their lock hashes in the inputs. It keeps
+/// the capacity of the group in cells of this lock with the recovery pending flag and the new
+/// lock hash added to the args, which only this path produces from the passport cells.
+/// The finalization spends those pending cells once every one of them waited the delay, and
+/// moves their capacity to the new lock. It needs no signature, so every pending cell is paid
+/// by the output at the index of its input, which no other group or script counts as its own
+/// payment. Until then the passport cancels the recovery by spending the pending cells as
+/// usual.
+pub fn verify_recovery(lock_args: &LockArgs, witness: &[u8], payload: &[u8]) -> Result<(), Error> {
+  let reader = RecoveryWitnessReader::from_slice(witness).map_err(|_| Error::Encoding)?;
+  if reader.version().as_slice()[0] != WITNESS_VERSION {
+    return Err(Error::UnsupportedWitnessVersion);
+  }
+  let group_capacity = QueryIter::new(load_cell_capacity, Source::GroupInput).sum::<u64>();
+
+  let mut delay = [0u8; 8];
+  delay.copy_from_slice(&payload[GUARDIAN_HASH_LEN..]);
+  let delay = u64::from_le_bytes(delay);
+  if delay & since::SINCE_RELATIVE_FLAG == 0 {
+    return Err(Error::InvalidArgument);
+  }
+
+  if let Some(new_lock_hash) = lock_args.payload(args::FLAG_RECOVERY_PENDING) {
+    since::check_group_since(delay)?;
+    return check_paid_in_place(new_lock_hash);
+  }
+  let new_lock_hash = reader.new_lock_hash().raw_data();
+
+  let threshold = reader.threshold().as_slice()[0] as usize;
+  let guardians = reader.guardian_lock_hashes();
+  let guardians: Vec<&[u8]> = (0..guardians.len())
+    .filter_map(|i| guardians.get(i))
+    .map(|guardian| guardian.raw_data())
+    .collect();
+  let count = guardians.len();
+  if threshold == 0 || threshold > count || count > u8::MAX as usize
+    || guardians.windows(2).any(|pair| pair[0] >= pair[1]) {
+    return Err(Error::InvalidGuardianConfig);
+  }
+
+  let mut config = Vec::with_capacity(2 + count * LOCK_HASH_LEN);
+  config.push(threshold as u8);
+  config.push(count as u8);
+  for guardian in guardians.iter() {
+    config.extend_from_slice(guardian);
+  }
+  if hash::blake2b_160(config)[..] != payload[..GUARDIAN_HASH_LEN] {
+    return Err(Error::WrongPubKey);
+  }
+
+  let input_lock_hashes: Vec<[u8; 32]> = QueryIter::new(load_cell_lock_hash, Source::Input).collect();
+  let present = guardians
+    .iter()
+    .filter(|guardian| input_lock_hashes.iter().any(|lock_hash| lock_hash[..] == guardian[..]))
+    .count();
+  if present < threshold {
+    return Err(Error::GuardiansNotPresent);
+  }
+
+  if new_lock_hash[..] == load_script_hash()?[..] {
+    return Err(Error::RecoveryOutputMismatch);
+  }
+  let pending_lock = load_script()?
+    .as_builder()
+    .args(Bytes::from(lock_args.with_payload(args::FLAG_RECOVERY_PENDING, new_lock_hash)).pack())
+    .build();
+  let pending_lock_hash = hash::blake2b_256(pending_lock.as_slice());
+  if output_capacity(|lock_hash, _| *lock_hash == pending_lock_hash) < group_capacity {
+    return Err(Error::RecoveryOutputMismatch);
+  }
+  Ok(())
+}
+
+/// Checks that the output at the index of every input of the group pays its capacity to the
+/// new lock
+fn check_paid_in_place(new_lock_hash: &[u8]) -> Result<(), Error> {
+  let script_hash = load_script_hash()?;
+  for (index, lock_hash) in QueryIter::new(load_cell_lock_hash, Source::Input).enumerate() {
+    if lock_hash != script_hash {
+      continue;
+    }
+    let output_lock_hash = load_cell_lock_hash(index, Source::Output).map_err(|_| Error::RecoveryOutputMismatch)?;
+    if output_lock_hash[..] != new_lock_hash[..]
+      || load_cell_capacity(index, Source::Output)? < load_cell_capacity(index, Source::Input)?
+    {
+      return Err(Error::RecoveryOutputMismatch);
+    }
+  }
+  Ok(())
+}
+
+/// Sums the capacity of the outputs whose lock hash and index match
+pub(super) fn output_capacity<F: Fn(&[u8; 32], usize) -> bool>(matches: F) -> u64 {
+  QueryIter::new(load_cell_lock_hash, Source::Output)
+    .enumerate()
+    .filter(|(index, lock_hash)| matches(lock_hash, *index))
+    .filter_map(|(index, _)| load_cell_capacity(index, Source::Output).ok())
+    .sum()
+}
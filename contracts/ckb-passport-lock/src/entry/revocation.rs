@@ -0,0 +1,84 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_std::{
+  ckb_constants::Source,
+  ckb_types::prelude::*,
+  error::SysError,
+  high_level::{load_cell_type_hash, QueryIter},
+  syscalls::load_cell_data,
+};
+use ckb_passport_lock_types::RevocationWitnessReader;
+use crate::error::Error;
+
+const PUB_KEY_HASH_LEN: usize = 20;
+
+/// The witness lock of a passport under a revocation registry is a RevocationWitness, which
+/// holds the position of every signer in the registry, in signer order, and wraps the witness
+/// lock of the signatures
+pub fn unwrap_witness(witness: &[u8]) -> Result<(Vec<u32>, &[u8]), Error> {
+  let reader = RevocationWitnessReader::from_slice(witness).map_err(|_| Error::Encoding)?;
+  let positions = reader.positions();
+  let positions = (0..positions.len())
+    .filter_map(|i| positions.get(i))
+    .map(|position| {
+      let mut value = [0u8; 4];
+      value.copy_from_slice(position.raw_data());
+      u32::from_le_bytes(value)
+    })
+    .collect();
+  Ok((positions, reader.lock().raw_data()))
+}
+
+/// The revoked passports are listed in a registry cell, which must be the only cell dep with
+/// the type script hash of the args. Its data are the revoked pubkey hashes in strictly
+/// ascending order, which the ckb-passport-revocation-registry type script enforces along
+/// with the updater of the registry, both pinned by the type script hash.
+///
+/// The position of a signer is the number of revoked pubkey hashes below its own, only the
+/// two neighbours at that position are loaded so the cost does not depend on the size of the
+/// registry.
+pub fn check_not_revoked(registry_type_hash: &[u8], positions: &[u32], pub_key_hashes: &[&[u8]]) -> Result<(), Error> {
+  let mut registries = QueryIter::new(load_cell_type_hash, Source::CellDep)
+    .enumerate()
+    .filter(|(_, type_hash)| type_hash.map_or(false, |type_hash| type_hash[..] == registry_type_hash[..]))
+    .map(|(index, _)| index);
+  let index = registries.next().ok_or(Error::RevocationRegistryMissing)?;
+  if registries.next().is_some() {
+    return Err(Error::RevocationRegistryMissing);
+  }
+
+  let data_len = match load_cell_data(&mut [], 0, index, Source::CellDep) {
+    Ok(len) => len,
+    Err(SysError::LengthNotEnough(len)) => len,
+    Err(err) => return Err(err.into()),
+  };
+  if data_len % PUB_KEY_HASH_LEN != 0 {
+    return Err(Error::Encoding);
+  }
+  let revoked_count = data_len / PUB_KEY_HASH_LEN;
+
+  if positions.len() > pub_key_hashes.len() {
+    return Err(Error::WitnessTrailingBytes);
+  }
+  for (i, pub_key_hash) in pub_key_hashes.iter().enumerate() {
+    let position = *positions.get(i).ok_or(Error::PassportRevoked)? as usize;
+    if position > revoked_count {
+      return Err(Error::PassportRevoked);
+    }
+    if position > 0 && load_revoked(index, position - 1)?[..] >= pub_key_hash[..] {
+      return Err(Error::PassportRevoked);
+    }
+    if position < revoked_count && load_revoked(index, position)?[..] <= pub_key_hash[..] {
+      return Err(Error::PassportRevoked);
+    }
+  }
+  Ok(())
+}
+
+fn load_revoked(index: usize, position: usize) -> Result<[u8; PUB_KEY_HASH_LEN], Error> {
+  let mut pub_key_hash = [0u8; PUB_KEY_HASH_LEN];
+  match load_cell_data(&mut pub_key_hash, position * PUB_KEY_HASH_LEN, index, Source::CellDep) {
+    Ok(_) | Err(SysError::LengthNotEnough(_)) => Ok(pub_key_hash),
+    Err(err) => Err(err.into()),
+  }
+}
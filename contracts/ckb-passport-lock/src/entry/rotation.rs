@@ -0,0 +1,49 @@
+use core::result::Result;
+use ckb_std::{
+  ckb_constants::Source,
+  ckb_types::{bytes::Bytes, prelude::*},
+  high_level::{load_cell_capacity, load_script, QueryIter},
+};
+use crate::error::Error;
+use super::{args::{self, LockArgs, PUB_KEY_HASH_LEN}, hash, recovery, since};
+
+/// Rotation moves the cells of an expiring passport to the pubkey hash of its renewed
+/// replacement, which the old passport signs as any other spend. The grace mode lets the
+/// successor designated in the args, which only the old passport sets, claim the cells alone
+/// once the old one expired, in two steps.
+///
+/// The claim request, signed by the successor after the expiry, keeps the capacity of the
+/// group in cells of this lock with the grace claimed flag set in the args. The successor
+/// spends those claimed cells once every one of them waited the delay. Only the request, after
+/// the expiry, or the old passport produce claimed cells, and until the delay passed the old
+/// passport cancels the claim by spending them as usual.
+pub fn verify_grace_claim(lock_args: &LockArgs, new_pub_key_hash: &[u8; PUB_KEY_HASH_LEN], payload: &[u8]) -> Result<(), Error> {
+  let mut expiry = [0u8; 8];
+  expiry.copy_from_slice(&payload[..8]);
+  let expiry = u64::from_le_bytes(expiry);
+  let mut delay = [0u8; 8];
+  delay.copy_from_slice(&payload[8..16]);
+  let delay = u64::from_le_bytes(delay);
+  if expiry & since::SINCE_RELATIVE_FLAG != 0 || delay & since::SINCE_RELATIVE_FLAG == 0 {
+    return Err(Error::InvalidArgument);
+  }
+  if payload[16..] != new_pub_key_hash[..] {
+    return Err(Error::WrongPubKey);
+  }
+
+  if lock_args.has_flag(args::FLAG_GRACE_CLAIMED) {
+    return since::check_group_since(delay);
+  }
+
+  since::check_group_since(expiry)?;
+  let claimed_lock = load_script()?
+    .as_builder()
+    .args(Bytes::from(lock_args.with_flags(lock_args.flags | args::FLAG_GRACE_CLAIMED)).pack())
+    .build();
+  let claimed_lock_hash = hash::blake2b_256(claimed_lock.as_slice());
+  let group_capacity = QueryIter::new(load_cell_capacity, Source::GroupInput).sum::<u64>();
+  if recovery::output_capacity(|lock_hash, _| *lock_hash == claimed_lock_hash) < group_capacity {
+    return Err(Error::GraceClaimOutputMismatch);
+  }
+  Ok(())
+}
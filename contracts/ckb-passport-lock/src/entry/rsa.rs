@@ -3,11 +3,11 @@ use alloc::vec::Vec;
 use ckb_lib_rsa::LibRSA;
 use crate::error::Error;
 
-pub fn verify_iso9796_2_signature(lib: &LibRSA, n: &[u8], e: u32, msg: &[u8], sig: &[u8]) -> Result<(), Error> {
-  let rsa_info = generate_rsa_info(&n, e, &sig)?;
+pub fn verify_rsa_signature(lib: &LibRSA, header: &[u8; 4], n: &[u8], e: u32, msg: &[u8], sig: &[u8]) -> Result<(), Error> {
+  let rsa_info = generate_rsa_info(header, &n, e, &sig)?;
   match lib.validate_signature(rsa_info.as_ref(), &msg) {
     Ok(_) => Ok(()),
-    Err(err) => match err {
+    Err(err) if header[0] == ISO9796_2_ALGORITHM_ID => match err {
       52 => Err(Error::ISO97962MismatchHash),
       53 => Err(Error::ISO97962InvalidArg1),
       54 => Err(Error::ISO97962InvalidArg2),
@@ -19,7 +19,8 @@ pub fn verify_iso9796_2_signature(lib: &LibRSA, n: &[u8], e: u32, msg: &[u8], si
       60 => Err(Error::ISO97962InvalidArg8),
       61 => Err(Error::ISO97962InvalidArg9),
       _ => Err(Error::ISO97962RSAVerifyError)
-    }
+    },
+    Err(_) => Err(Error::RSAVerifyError)
   }
 }
 
@@ -34,11 +35,11 @@ pub fn verify_iso9796_2_signature(lib: &LibRSA, n: &[u8], e: u32, msg: &[u8], si
 -----------------------------------------------------------------------------
 The common header includes algorithm_id, key_size, padding, md_type whose data type are uint8_t.
 The common header, E both occupy 4 bytes. E is in little endian(uint32_t).
-The N must be little endian with [u8; 128]
+The N must be little endian with [u8; KeySize/8]
 So the total length in byte is: 4 + 4 + KeySize/8 + KeySize/8.
 */
-fn generate_rsa_info(n: &[u8], e: u32, sig: &[u8]) -> Result<Vec<u8>, Error> {
-  if n.len() != sig.len() {
+fn generate_rsa_info(header: &[u8; 4], n: &[u8], e: u32, sig: &[u8]) -> Result<Vec<u8>, Error> {
+  if n.len() != sig.len() || get_key_len(header[1])? != n.len() {
     return Err(Error::RSAPubKeySigLengthError)
   }
 
@@ -50,7 +51,7 @@ fn generate_rsa_info(n: &[u8], e: u32, sig: &[u8]) -> Result<Vec<u8>, Error> {
     rsa_info.push(0u8);
   }
 
-  rsa_info[0..4].copy_from_slice(&get_common_header());
+  rsa_info[0..4].copy_from_slice(header);
   rsa_info[4..8].copy_from_slice(&e.to_le_bytes());
   rsa_info[8..(8 + n.len())].copy_from_slice(&n);
   rsa_info[(8 + n.len())..(8 + n.len() * 2)].copy_from_slice(&sig);
@@ -58,10 +59,46 @@ fn generate_rsa_info(n: &[u8], e: u32, sig: &[u8]) -> Result<Vec<u8>, Error> {
   Ok(rsa_info)
 }
 
-const ISO9796_2_ALGORITHM_ID: u8 = 2;
-const ISO9796_2_KEY_SIZE: u8 = 1;
+// algorithm ids of validate_signature_rsa
+pub const RSA_ALGORITHM_ID: u8 = 1;
+pub const ISO9796_2_ALGORITHM_ID: u8 = 2;
+
 const ISO9796_2_PADDING: u8 = 0;
-const ISO9796_2_MD_SHA1: u8 = 4;
-pub fn get_common_header() -> [u8; 4] {
-  [ISO9796_2_ALGORITHM_ID, ISO9796_2_KEY_SIZE, ISO9796_2_PADDING, ISO9796_2_MD_SHA1]
+pub const PKCS1_V15_PADDING: u8 = 0;
+pub const PKCS1_PSS_PADDING: u8 = 1;
+
+pub const ISO9796_2_KEY_SIZE_1024: u8 = 1;
+pub const ISO9796_2_KEY_SIZE_2048: u8 = 2;
+pub const ISO9796_2_KEY_SIZE_4096: u8 = 3;
+
+/// Key sizes understood by validate_signature_rsa as (key_size id, N length in bytes).
+/// The library has no id for 1536 and 3072-bit keys, so those cannot be verified.
+pub const KEY_SIZES: [(u8, usize); 3] = [
+  (ISO9796_2_KEY_SIZE_1024, 128),
+  (ISO9796_2_KEY_SIZE_2048, 256),
+  (ISO9796_2_KEY_SIZE_4096, 512),
+];
+
+/// Returns the length in bytes of N (and of every sub-signature) for a key_size id
+pub fn get_key_len(key_size: u8) -> Result<usize, Error> {
+  KEY_SIZES
+    .iter()
+    .find(|(id, _)| *id == key_size)
+    .map(|(_, n_len)| *n_len)
+    .ok_or(Error::UnsupportedKeySize)
+}
+
+/// Checks the padding of an RSA algorithm id, ISO 9796-2 has a single padding and
+/// PKCS#1 signatures are either v1.5 or PSS
+pub fn check_padding(algorithm_id: u8, padding: u8) -> Result<(), Error> {
+  match algorithm_id {
+    ISO9796_2_ALGORITHM_ID if padding == ISO9796_2_PADDING => Ok(()),
+    RSA_ALGORITHM_ID if padding == PKCS1_V15_PADDING || padding == PKCS1_PSS_PADDING => Ok(()),
+    ISO9796_2_ALGORITHM_ID | RSA_ALGORITHM_ID => Err(Error::UnsupportedPadding),
+    _ => Err(Error::UnsupportedAlgorithm),
+  }
+}
+
+pub fn get_common_header(algorithm_id: u8, key_size: u8, padding: u8, md_type: u8) -> [u8; 4] {
+  [algorithm_id, key_size, padding, md_type]
 }
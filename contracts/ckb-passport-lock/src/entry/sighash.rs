@@ -0,0 +1,79 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_std::{
+  ckb_constants::Source,
+  ckb_types::{packed::Transaction, prelude::*},
+  high_level::{load_input_out_point, load_transaction, QueryIter},
+};
+use crate::error::Error;
+use super::hash::Blake2b;
+
+/// The signatures cover the tx hash, as the witness locks without a sighash type
+pub const SIGHASH_ALL: u8 = 0x01;
+/// The signatures cover the outputs at the indices of the group inputs only
+pub const SIGHASH_SINGLE: u8 = 0x03;
+/// The signatures cover the group inputs only, combined with SIGHASH_ALL or SIGHASH_SINGLE
+pub const SIGHASH_ANYONECANPAY: u8 = 0x80;
+
+/// Digests, in place of the tx hash, the sighash type and the part of the transaction it
+/// covers, so that others may complete the transaction after the passport signed:
+/// - SIGHASH_ALL | SIGHASH_ANYONECANPAY: the group inputs and every output
+/// - SIGHASH_SINGLE: every input and the outputs at the indices of the group inputs
+/// - SIGHASH_SINGLE | SIGHASH_ANYONECANPAY: the group inputs and the outputs at their indices
+///
+/// Inputs are digested as CellInput, outputs as CellOutput followed by the length and the
+/// content of their data. Cell deps and header deps are never covered.
+pub fn digest_tx(blake2b: &mut Blake2b, sighash_type: u8) -> Result<(), Error> {
+  let base_type = sighash_type & !SIGHASH_ANYONECANPAY;
+  if sighash_type == SIGHASH_ALL || (base_type != SIGHASH_ALL && base_type != SIGHASH_SINGLE) {
+    return Err(Error::UnsupportedSighashType);
+  }
+  blake2b.update(&[sighash_type]);
+
+  let tx = load_transaction()?;
+  let inputs = tx.raw().inputs();
+  let group_indices = group_input_indices(&tx)?;
+  if sighash_type & SIGHASH_ANYONECANPAY != 0 {
+    for index in group_indices.iter() {
+      blake2b.update(inputs.get(*index).ok_or(Error::IndexOutOfBound)?.as_slice());
+    }
+  } else {
+    for input in inputs.into_iter() {
+      blake2b.update(input.as_slice());
+    }
+  }
+
+  if base_type == SIGHASH_ALL {
+    for index in 0..tx.raw().outputs().len() {
+      digest_output(blake2b, &tx, index)?;
+    }
+  } else {
+    for index in group_indices.iter() {
+      digest_output(blake2b, &tx, *index)?;
+    }
+  }
+  Ok(())
+}
+
+/// Finds the indices of the group inputs among the transaction inputs
+fn group_input_indices(tx: &Transaction) -> Result<Vec<usize>, Error> {
+  let inputs = tx.raw().inputs();
+  QueryIter::new(load_input_out_point, Source::GroupInput)
+    .map(|out_point| {
+      inputs
+        .clone()
+        .into_iter()
+        .position(|input| input.previous_output().as_slice() == out_point.as_slice())
+        .ok_or(Error::IndexOutOfBound)
+    })
+    .collect()
+}
+
+fn digest_output(blake2b: &mut Blake2b, tx: &Transaction, index: usize) -> Result<(), Error> {
+  let output = tx.raw().outputs().get(index).ok_or(Error::SighashOutputMissing)?;
+  let data = tx.raw().outputs_data().get(index).ok_or(Error::SighashOutputMissing)?.raw_data();
+  blake2b.update(output.as_slice());
+  blake2b.update(&(data.len() as u64).to_le_bytes());
+  blake2b.update(&data);
+  Ok(())
+}
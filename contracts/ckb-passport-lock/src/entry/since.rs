@@ -0,0 +1,70 @@
+use core::result::Result;
+use ckb_std::{ckb_constants::Source, high_level::{load_input_since, QueryIter}};
+use crate::error::Error;
+
+/// The highest bit of since tells a relative one, the next two bits the metric
+pub const SINCE_RELATIVE_FLAG: u64 = 0x8000_0000_0000_0000;
+const SINCE_FLAGS_MASK: u64 = 0xFF00_0000_0000_0000;
+const SINCE_VALUE_MASK: u64 = 0x00FF_FFFF_FFFF_FFFF;
+const SINCE_METRIC_MASK: u64 = 0x6000_0000_0000_0000;
+const SINCE_METRIC_EPOCH: u64 = 0x2000_0000_0000_0000;
+/// Only the relative flag and the metric may be set
+const SINCE_VALID_FLAGS: u64 = 0xE000_0000_0000_0000;
+
+/// Checks that every input of the script group has a since of the same kind as required
+/// and not lower than it
+pub fn check_group_since(required: u64) -> Result<(), Error> {
+  check_flags(required)?;
+  for since in QueryIter::new(load_input_since, Source::GroupInput) {
+    if since & SINCE_FLAGS_MASK != required & SINCE_FLAGS_MASK {
+      return Err(Error::SinceMismatch);
+    }
+    if !since_reached(required, since)? {
+      return Err(Error::SinceNotSatisfied);
+    }
+  }
+  Ok(())
+}
+
+/// Returns the epoch number of an absolute epoch since
+pub fn absolute_epoch_number(since: u64) -> Option<u64> {
+  if since & SINCE_FLAGS_MASK != SINCE_METRIC_EPOCH {
+    return None;
+  }
+  Some(decode_epoch(since & SINCE_VALUE_MASK).0)
+}
+
+fn check_flags(since: u64) -> Result<(), Error> {
+  if since & SINCE_FLAGS_MASK & !SINCE_VALID_FLAGS != 0 || since & SINCE_METRIC_MASK == SINCE_METRIC_MASK {
+    return Err(Error::InvalidArgument);
+  }
+  Ok(())
+}
+
+/// Compares the values of two since of the same metric, epochs are compared as
+/// number + index / length
+fn since_reached(required: u64, since: u64) -> Result<bool, Error> {
+  let required_value = required & SINCE_VALUE_MASK;
+  let value = since & SINCE_VALUE_MASK;
+  if required & SINCE_METRIC_MASK != SINCE_METRIC_EPOCH {
+    return Ok(value >= required_value);
+  }
+
+  let (required_number, required_index, required_length) = decode_epoch(required_value);
+  let (number, index, length) = decode_epoch(value);
+  if required_length == 0 {
+    return Err(Error::InvalidArgument);
+  }
+  if length == 0 {
+    return Err(Error::SinceNotSatisfied);
+  }
+  if number != required_number {
+    return Ok(number > required_number);
+  }
+  Ok(index * required_length >= required_index * length)
+}
+
+/// Epoch number (24 bits), index (16 bits) and length (16 bits)
+fn decode_epoch(value: u64) -> (u64, u64, u64) {
+  ((value & 0xFF_FFFF), (value >> 24) & 0xFFFF, (value >> 40) & 0xFFFF)
+}
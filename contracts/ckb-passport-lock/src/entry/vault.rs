@@ -0,0 +1,57 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_std::{
+  ckb_constants::Source,
+  ckb_types::{bytes::Bytes, prelude::*},
+  high_level::{load_cell_capacity, load_script, QueryIter},
+};
+use crate::error::Error;
+use super::{args::{self, LockArgs}, backup, hash, recovery, since};
+
+const RECOVERY_KEY_HASH_LEN: usize = 20;
+
+/// A passport signature only moves vaulted cells to the unvaulting state, which is this lock
+/// with the unvaulting flag set in the args, or back to the vault, so a part of them may
+/// unvault. The passport spends the unvaulting cells freely once every one of them waited the
+/// delay.
+pub fn check_passport_spend(lock_args: &LockArgs, payload: &[u8]) -> Result<(), Error> {
+  let delay = parse_delay(payload)?;
+  if lock_args.has_flag(args::FLAG_UNVAULTING) {
+    return since::check_group_since(delay);
+  }
+  check_outputs(lock_args, &[lock_args.flags, lock_args.flags | args::FLAG_UNVAULTING])
+}
+
+/// Until the delay passed the recovery key sweeps the unvaulting cells back to the vault, the
+/// witness lock is then a secp256k1 signature as for the backup key
+pub fn sweep(lock_args: &LockArgs, payload: &[u8]) -> Result<(), Error> {
+  parse_delay(payload)?;
+  backup::verify_backup_key(&payload[..RECOVERY_KEY_HASH_LEN])?;
+  check_outputs(lock_args, &[lock_args.flags & !args::FLAG_UNVAULTING])
+}
+
+fn parse_delay(payload: &[u8]) -> Result<u64, Error> {
+  let mut delay = [0u8; 8];
+  delay.copy_from_slice(&payload[RECOVERY_KEY_HASH_LEN..]);
+  let delay = u64::from_le_bytes(delay);
+  if delay & since::SINCE_RELATIVE_FLAG == 0 {
+    return Err(Error::InvalidArgument);
+  }
+  Ok(delay)
+}
+
+/// Checks that the outputs of this lock with any of the flags in the args keep the group
+/// capacity
+fn check_outputs(lock_args: &LockArgs, flags: &[u32]) -> Result<(), Error> {
+  let script = load_script()?;
+  let mut lock_hashes = Vec::with_capacity(flags.len());
+  for flags in flags {
+    let lock = script.clone().as_builder().args(Bytes::from(lock_args.with_flags(*flags)).pack()).build();
+    lock_hashes.push(hash::blake2b_256(lock.as_slice()));
+  }
+  let group_capacity = QueryIter::new(load_cell_capacity, Source::GroupInput).sum::<u64>();
+  if recovery::output_capacity(|output_lock_hash, _| lock_hashes.contains(output_lock_hash)) < group_capacity {
+    return Err(Error::VaultOutputMismatch);
+  }
+  Ok(())
+}
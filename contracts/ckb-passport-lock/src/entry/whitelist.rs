@@ -0,0 +1,54 @@
+use core::result::Result;
+use ckb_std::{
+  ckb_constants::Source,
+  ckb_types::prelude::*,
+  high_level::{load_cell_lock_hash, load_script_hash, QueryIter},
+};
+use ckb_passport_lock_types::{Byte32VecReader, WhitelistWitnessReader};
+use crate::error::Error;
+use super::hash;
+
+/// The passport may only send to its own lock and to the whitelisted locks. The witness lock
+/// is then a WhitelistWitness, which holds a merkle proof for every output of another lock,
+/// in output order, and wraps the witness lock of the signatures.
+///
+/// The leaves of the merkle tree are the allowed lock hashes, a node is the blake2b of its
+/// children in ascending order.
+pub fn check_outputs<'a>(witness: &'a [u8], root: &[u8]) -> Result<&'a [u8], Error> {
+  let reader = WhitelistWitnessReader::from_slice(witness).map_err(|_| Error::Encoding)?;
+  let proofs = reader.proofs();
+  let script_hash = load_script_hash()?;
+
+  let mut proof_count = 0;
+  for lock_hash in QueryIter::new(load_cell_lock_hash, Source::Output) {
+    if lock_hash == script_hash {
+      continue;
+    }
+    let proof = proofs.get(proof_count).ok_or(Error::OutputNotWhitelisted)?;
+    proof_count += 1;
+    if compute_root(lock_hash, proof)[..] != root[..] {
+      return Err(Error::OutputNotWhitelisted);
+    }
+  }
+  if proof_count != proofs.len() {
+    return Err(Error::WitnessTrailingBytes);
+  }
+  Ok(reader.lock().raw_data())
+}
+
+fn compute_root(leaf: [u8; 32], proof: Byte32VecReader) -> [u8; 32] {
+  let mut node = leaf;
+  let mut children = [0u8; 64];
+  for sibling in (0..proof.len()).filter_map(|i| proof.get(i)) {
+    let sibling = sibling.raw_data();
+    if node[..] <= sibling[..] {
+      children[..32].copy_from_slice(&node);
+      children[32..].copy_from_slice(sibling);
+    } else {
+      children[..32].copy_from_slice(sibling);
+      children[32..].copy_from_slice(&node);
+    }
+    node = hash::blake2b_256(&children[..]);
+  }
+  node
+}
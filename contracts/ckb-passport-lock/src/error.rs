@@ -22,6 +22,50 @@ pub enum Error {
     ISO97962InvalidArg8,
     ISO97962InvalidArg9,
     ISO97962MismatchHash = 18,
+    UnsupportedKeySize,
+    UnsupportedDigest = 20,
+    DigestTooWeak,
+    UnsupportedCurve,
+    ECDSAPubKeySigLengthError,
+    ECDSAInvalidPubKey,
+    ECDSAInvalidSignature = 25,
+    ECDSAVerifyError,
+    UnsupportedAlgorithm,
+    UnsupportedPadding,
+    RSAVerifyError,
+    UnsupportedChunkSize = 30,
+    UnsupportedWitnessVersion,
+    WitnessTooShort,
+    WitnessTrailingBytes,
+    UnsupportedHeader,
+    UnsupportedArgsFlags = 35,
+    InvalidMultisigConfig,
+    MultisigThresholdNotMet,
+    DuplicateSigner,
+    Secp256k1VerifyError,
+    SinceNotSatisfied = 40,
+    InvalidGuardianConfig,
+    GuardiansNotPresent,
+    RecoveryOutputMismatch,
+    SinceMismatch,
+    BeneficiaryNotPresent = 45,
+    GraceClaimOutputMismatch,
+    PaymentOutputMismatch,
+    PaymentTooSmall,
+    UnsupportedSighashType,
+    SighashOutputMissing = 50,
+    SpendingLimitExceeded,
+    OutputNotWhitelisted,
+    VaultOutputMismatch,
+    RevocationRegistryMissing,
+    PassportRevoked = 55,
+    DelegationMissing,
+    DelegationCellSpent,
+    DelegationCapExceeded,
+    DelegationOutputMismatch,
+    PassportNotRegistered = 60,
+    DocumentMismatch,
+    SpendingStateMismatch,
 }
 
 impl From<SysError> for Error {
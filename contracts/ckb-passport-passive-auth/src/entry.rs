@@ -0,0 +1,186 @@
+// Import from `core` instead of from `std` since we are in no-std mode
+use core::result::Result;
+use alloc::vec::Vec;
+
+use blake2b_ref::Blake2bBuilder;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, prelude::*},
+    high_level::{load_cell_data, load_cell_type_hash, load_script, load_witness_args, QueryIter},
+};
+use ckb_passport_lock_types::{BytesVecReader, PassiveAuthWitnessReader};
+use crate::error::Error;
+
+mod der;
+mod sod;
+mod verify;
+mod x509;
+
+use verify::{ECDSA_ALGORITHM_ID, ISO9796_2_ALGORITHM_ID, RSA_ALGORITHM_ID};
+use x509::PublicKey;
+
+const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
+const MASTER_LIST_TYPE_HASH_LEN: usize = 32;
+const PUB_KEY_HASH_LEN: usize = 20;
+const COMMON_HEADER: usize = 4;
+
+/// Data group of the active authentication public key, wrapped in its application tag
+const DG15: u8 = 15;
+const TAG_DG15: u8 = 0x6F;
+
+/// Type script of the registration cells of ckb-passport-lock, which attest that a passport
+/// passed ICAO 9303 passive authentication. The args are the type script hash of a master list
+/// cell, whose data are the SubjectPublicKeyInfo of the trusted CSCAs as a BytesVec, and the
+/// data of a registration cell are the pubkey hash of the lock args of the passport, optionally
+/// followed by the blake2b of the LDSSecurityObject of its EF.SOD for the document binding of
+/// the lock.
+///
+/// At creation, the output_type of the witness of every cell is a PassiveAuthWitness with the
+/// EF.SOD and EF.DG15 read from the chip, the common header of the lock and the index of the
+/// CSCA in the master list. The CSCA must sign the document signer certificate of the SOD, the
+/// document signer the security object, which must hold the hash of DG15, and the cell data
+/// must be the pubkey hash of the active authentication key of DG15 under the header.
+pub fn main() -> Result<(), Error> {
+    let script = load_script()?;
+    let args: Bytes = script.args().unpack();
+    if args.len() != MASTER_LIST_TYPE_HASH_LEN {
+        return Err(Error::InvalidArgument);
+    }
+
+    // Cells may be destroyed freely, only a transaction creating some needs the master list
+    let mut master_list = None;
+    for (index, data) in QueryIter::new(load_cell_data, Source::GroupOutput).enumerate() {
+        if master_list.is_none() {
+            master_list = Some(load_master_list(&args)?);
+        }
+        let witness_args = load_witness_args(index, Source::GroupOutput)?;
+        let witness: Bytes = witness_args.output_type().to_opt().ok_or(Error::Encoding)?.unpack();
+        let witness = PassiveAuthWitnessReader::from_slice(&witness).map_err(|_| Error::Encoding)?;
+        let (pub_key_hash, security_object_hash) = verify_passport(master_list.as_ref().unwrap(), witness)?;
+        if data.len() != PUB_KEY_HASH_LEN && data.len() != PUB_KEY_HASH_LEN + security_object_hash.len() {
+            return Err(Error::Encoding);
+        }
+        if data[..PUB_KEY_HASH_LEN] != pub_key_hash[..] {
+            return Err(Error::AaKeyMismatch);
+        }
+        if data.len() > PUB_KEY_HASH_LEN && data[PUB_KEY_HASH_LEN..] != security_object_hash[..] {
+            return Err(Error::SecurityObjectMismatch);
+        }
+    }
+    Ok(())
+}
+
+/// Loads the data of the only cell dep with the master list type hash
+fn load_master_list(type_hash: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut indices = QueryIter::new(load_cell_type_hash, Source::CellDep)
+        .enumerate()
+        .filter(|(_, hash)| hash.map_or(false, |hash| hash[..] == type_hash[..]))
+        .map(|(index, _)| index);
+    let index = indices.next().ok_or(Error::MasterListMissing)?;
+    if indices.next().is_some() {
+        return Err(Error::MasterListMissing);
+    }
+    let data = load_cell_data(index, Source::CellDep)?;
+    BytesVecReader::verify(&data, false).map_err(|_| Error::Encoding)?;
+    Ok(data)
+}
+
+/// Runs passive authentication, returns the pubkey hash of the active authentication key and
+/// the blake2b of the security object
+fn verify_passport(master_list: &[u8], witness: PassiveAuthWitnessReader) -> Result<([u8; PUB_KEY_HASH_LEN], [u8; 32]), Error> {
+    let cscas = BytesVecReader::new_unchecked(master_list);
+    let csca_index: u32 = u32::from_le_bytes(copy_array(witness.csca_index().raw_data()));
+    let csca = cscas.get(csca_index as usize).ok_or(Error::CscaMissing)?;
+    let csca_key = x509::parse_public_key(csca.raw_data())?;
+    let sod = sod::parse_sod(witness.sod().raw_data())?;
+
+    // The CSCA signs the document signer certificate
+    let certificate = &sod.certificate;
+    let md_type = certificate.algorithm.md_type().ok_or(Error::UnsupportedAlgorithm)?;
+    verify::verify_signature(
+        &csca_key,
+        &certificate.algorithm,
+        md_type,
+        certificate.tbs,
+        certificate.signature,
+        Error::CertificateVerifyError,
+    )?;
+
+    // The document signer signs the attributes, among them the content type and the digest of
+    // the security object. The signature covers their DER encoding as a SET OF rather than the
+    // [0] IMPLICIT tag.
+    if sod::content_type(sod.signed_attributes)? != sod::OID_LDS_SECURITY_OBJECT {
+        return Err(Error::ContentTypeMismatch);
+    }
+    let (digest, digest_len) = compute_digest(sod.digest_md_type, sod.security_object)?;
+    if sod::message_digest(sod.signed_attributes)? != &digest[..digest_len] {
+        return Err(Error::MessageDigestMismatch);
+    }
+    let mut signed_attributes = sod.signed_attributes.to_vec();
+    signed_attributes[0] = der::TAG_SET;
+    verify::verify_signature(
+        &certificate.public_key,
+        &sod.signature_algorithm,
+        sod.signature_algorithm.md_type().unwrap_or(sod.digest_md_type),
+        &signed_attributes,
+        sod.signature,
+        Error::SignerVerifyError,
+    )?;
+
+    // The security object holds the hash of DG15, which holds the active authentication key
+    let dg15 = witness.dg15().raw_data();
+    let (md_type, hash) = sod::data_group_hash(sod.security_object, DG15)?;
+    let (digest, digest_len) = compute_digest(md_type, dg15)?;
+    if hash != &digest[..digest_len] {
+        return Err(Error::DataGroupHashMismatch);
+    }
+    let aa_key = x509::parse_public_key(der::parse(dg15, TAG_DG15)?.content)?;
+
+    let header = witness.header().raw_data();
+    let mut data = Vec::from(header);
+    append_lock_pub_key(&mut data, header, &aa_key)?;
+    let mut pub_key_hash = [0u8; PUB_KEY_HASH_LEN];
+    pub_key_hash.copy_from_slice(&blake2b_256(&data)[..PUB_KEY_HASH_LEN]);
+    Ok((pub_key_hash, blake2b_256(sod.security_object)))
+}
+
+/// Appends the public key as a witness lock of ckb-passport-lock carries it with the header,
+/// E || N little endian for RSA and the compressed point for ECDSA
+fn append_lock_pub_key(data: &mut Vec<u8>, header: &[u8], key: &PublicKey) -> Result<(), Error> {
+    debug_assert_eq!(header.len(), COMMON_HEADER);
+    match key {
+        PublicKey::Rsa { n, e } if header[0] == RSA_ALGORITHM_ID || header[0] == ISO9796_2_ALGORITHM_ID => {
+            if verify::rsa_key_size(n)? != header[1] {
+                return Err(Error::AaKeyMismatch);
+            }
+            data.extend_from_slice(&e.to_le_bytes());
+            data.extend(n.iter().rev());
+        }
+        PublicKey::Ecdsa { curve, point } if header[0] == ECDSA_ALGORITHM_ID => {
+            if *curve != header[1] {
+                return Err(Error::AaKeyMismatch);
+            }
+            data.extend_from_slice(point);
+        }
+        _ => return Err(Error::AaKeyMismatch),
+    }
+    Ok(())
+}
+
+fn compute_digest(md_type: u8, message: &[u8]) -> Result<([u8; ckb_lib_ecdsa::MAX_DIGEST_LEN], usize), Error> {
+    ckb_lib_ecdsa::digest(md_type, message).ok_or(Error::UnsupportedDigest)
+}
+
+fn copy_array(data: &[u8]) -> [u8; 4] {
+    let mut array = [0u8; 4];
+    array.copy_from_slice(data);
+    array
+}
+
+fn blake2b_256(data: &[u8]) -> [u8; 32] {
+    let mut hash = [0u8; 32];
+    let mut blake2b = Blake2bBuilder::new(32).personal(CKB_HASH_PERSONALIZATION).build();
+    blake2b.update(data);
+    blake2b.finalize(&mut hash);
+    hash
+}
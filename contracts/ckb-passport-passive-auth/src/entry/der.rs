@@ -0,0 +1,103 @@
+use core::result::Result;
+use crate::error::Error;
+
+pub const TAG_INTEGER: u8 = 0x02;
+pub const TAG_BIT_STRING: u8 = 0x03;
+pub const TAG_OCTET_STRING: u8 = 0x04;
+pub const TAG_OID: u8 = 0x06;
+pub const TAG_SEQUENCE: u8 = 0x30;
+pub const TAG_SET: u8 = 0x31;
+pub const TAG_CONTEXT_0: u8 = 0xA0;
+pub const TAG_CONTEXT_1: u8 = 0xA1;
+
+/// Long form lengths of more bytes do not fit the data of a transaction
+const MAX_LENGTH_BYTES: usize = 4;
+
+/// A DER element, its content and its whole tag || length || content encoding
+pub struct Element<'a> {
+  pub tag: u8,
+  pub content: &'a [u8],
+  pub encoding: &'a [u8],
+}
+
+/// Reads consecutive DER elements. The passport data only use single byte tags and definite
+/// lengths, other encodings are rejected.
+pub struct Reader<'a> {
+  data: &'a [u8],
+}
+
+impl<'a> Reader<'a> {
+  pub fn new(data: &'a [u8]) -> Self {
+    Reader { data }
+  }
+
+  pub fn is_empty(&self) -> bool {
+    self.data.is_empty()
+  }
+
+  pub fn read_any(&mut self) -> Result<Element<'a>, Error> {
+    let tag = *self.data.first().ok_or(Error::Asn1Encoding)?;
+    if tag & 0x1F == 0x1F {
+      return Err(Error::Asn1Encoding);
+    }
+    let first = *self.data.get(1).ok_or(Error::Asn1Encoding)?;
+    let (len, header_len) = if first & 0x80 == 0 {
+      (first as usize, 2)
+    } else {
+      let count = (first & 0x7F) as usize;
+      if count == 0 || count > MAX_LENGTH_BYTES {
+        return Err(Error::Asn1Encoding);
+      }
+      let bytes = self.data.get(2..(2 + count)).ok_or(Error::Asn1Encoding)?;
+      (bytes.iter().fold(0usize, |len, byte| (len << 8) | *byte as usize), 2 + count)
+    };
+    let end = header_len.checked_add(len).ok_or(Error::Asn1Encoding)?;
+    let encoding = self.data.get(..end).ok_or(Error::Asn1Encoding)?;
+    self.data = &self.data[end..];
+    Ok(Element { tag, content: &encoding[header_len..], encoding })
+  }
+
+  pub fn read(&mut self, tag: u8) -> Result<Element<'a>, Error> {
+    let element = self.read_any()?;
+    if element.tag != tag {
+      return Err(Error::Asn1Encoding);
+    }
+    Ok(element)
+  }
+
+  /// Reads the next element only if it has the tag
+  pub fn read_optional(&mut self, tag: u8) -> Result<Option<Element<'a>>, Error> {
+    if self.data.first() == Some(&tag) {
+      self.read(tag).map(Some)
+    } else {
+      Ok(None)
+    }
+  }
+}
+
+/// Parses data which are exactly one element with the tag
+pub fn parse(data: &[u8], tag: u8) -> Result<Element, Error> {
+  let mut reader = Reader::new(data);
+  let element = reader.read(tag)?;
+  if !reader.is_empty() {
+    return Err(Error::Asn1Encoding);
+  }
+  Ok(element)
+}
+
+/// Returns the big endian magnitude of a non-negative INTEGER, without leading zeros
+pub fn unsigned_integer(content: &[u8]) -> Result<&[u8], Error> {
+  if content.first().map_or(true, |byte| byte & 0x80 != 0) {
+    return Err(Error::Asn1Encoding);
+  }
+  let start = content.iter().position(|byte| *byte != 0).unwrap_or(content.len());
+  Ok(&content[start..])
+}
+
+/// Returns the bytes of a BIT STRING, which must have no unused bits
+pub fn bit_string(content: &[u8]) -> Result<&[u8], Error> {
+  match content.split_first() {
+    Some((&0, bytes)) => Ok(bytes),
+    _ => Err(Error::Asn1Encoding),
+  }
+}
@@ -0,0 +1,121 @@
+use core::result::Result;
+use crate::error::Error;
+use super::der::{
+  self, Reader, TAG_CONTEXT_0, TAG_CONTEXT_1, TAG_INTEGER, TAG_OCTET_STRING, TAG_OID, TAG_SEQUENCE, TAG_SET,
+};
+use super::x509::{self, Certificate, SignatureAlgorithm};
+
+/// EF.SOD wraps the CMS ContentInfo in this application tag
+const TAG_EF_SOD: u8 = 0x77;
+
+const OID_SIGNED_DATA: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07, 0x02];
+const OID_CONTENT_TYPE: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x03];
+const OID_MESSAGE_DIGEST: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x04];
+/// id-icao-ldsSecurityObject, 2.23.136.1.1.1
+pub const OID_LDS_SECURITY_OBJECT: &[u8] = &[0x67, 0x81, 0x08, 0x01, 0x01, 0x01];
+
+/// The parts of an EF.SOD passive authentication verifies
+pub struct Sod<'a> {
+  /// Certificate of the document signer, the first of the SignedData certificates
+  pub certificate: Certificate<'a>,
+  /// Encoding of the LDSSecurityObject, the encapsulated content
+  pub security_object: &'a [u8],
+  /// The following are those of the first SignerInfo, signed_attributes is the encoding of
+  /// its [0] IMPLICIT signedAttrs
+  pub digest_md_type: u8,
+  pub signed_attributes: &'a [u8],
+  pub signature_algorithm: SignatureAlgorithm,
+  pub signature: &'a [u8],
+}
+
+/// Parses an EF.SOD, a CMS SignedData with the LDSSecurityObject of the passport as content,
+/// whose content type must be id-icao-ldsSecurityObject.
+pub fn parse_sod(data: &[u8]) -> Result<Sod, Error> {
+  let data = match Reader::new(data).read_optional(TAG_EF_SOD)? {
+    Some(element) => element.content,
+    None => data,
+  };
+
+  let mut content_info = Reader::new(der::parse(data, TAG_SEQUENCE)?.content);
+  if content_info.read(TAG_OID)?.content != OID_SIGNED_DATA {
+    return Err(Error::Asn1Encoding);
+  }
+  let signed_data = Reader::new(content_info.read(TAG_CONTEXT_0)?.content).read(TAG_SEQUENCE)?;
+
+  // version, digestAlgorithms, encapContentInfo, certificates, crls and signerInfos
+  let mut fields = Reader::new(signed_data.content);
+  fields.read(TAG_INTEGER)?;
+  fields.read(TAG_SET)?;
+  let mut encap_content_info = Reader::new(fields.read(TAG_SEQUENCE)?.content);
+  if encap_content_info.read(TAG_OID)?.content != OID_LDS_SECURITY_OBJECT {
+    return Err(Error::ContentTypeMismatch);
+  }
+  let content = encap_content_info.read(TAG_CONTEXT_0)?;
+  let security_object = Reader::new(content.content).read(TAG_OCTET_STRING)?.content;
+  let certificates = fields.read(TAG_CONTEXT_0)?;
+  let certificate = x509::parse_certificate(Reader::new(certificates.content).read(TAG_SEQUENCE)?.encoding)?;
+  fields.read_optional(TAG_CONTEXT_1)?;
+  let signer_infos = fields.read(TAG_SET)?;
+
+  // version, sid, digestAlgorithm, signedAttrs, signatureAlgorithm and signature
+  let mut signer_info = Reader::new(Reader::new(signer_infos.content).read(TAG_SEQUENCE)?.content);
+  signer_info.read(TAG_INTEGER)?;
+  signer_info.read_any()?;
+  let digest_md_type = x509::parse_digest_algorithm(signer_info.read(TAG_SEQUENCE)?.content)?;
+  let signed_attributes = signer_info.read(TAG_CONTEXT_0)?.encoding;
+  let signature_algorithm = x509::parse_signature_algorithm(signer_info.read(TAG_SEQUENCE)?.content)?;
+  let signature = signer_info.read(TAG_OCTET_STRING)?.content;
+
+  Ok(Sod {
+    certificate,
+    security_object,
+    digest_md_type,
+    signed_attributes,
+    signature_algorithm,
+    signature,
+  })
+}
+
+/// Returns the value of the contentType signed attribute
+pub fn content_type(signed_attributes: &[u8]) -> Result<&[u8], Error> {
+  let values = signed_attribute(signed_attributes, OID_CONTENT_TYPE)?.ok_or(Error::ContentTypeMismatch)?;
+  Ok(der::parse(values, TAG_OID)?.content)
+}
+
+/// Returns the value of the messageDigest signed attribute
+pub fn message_digest(signed_attributes: &[u8]) -> Result<&[u8], Error> {
+  let values = signed_attribute(signed_attributes, OID_MESSAGE_DIGEST)?.ok_or(Error::MessageDigestMismatch)?;
+  Ok(der::parse(values, TAG_OCTET_STRING)?.content)
+}
+
+/// Returns the content of the attrValues of a signed attribute
+fn signed_attribute<'a>(signed_attributes: &'a [u8], oid: &[u8]) -> Result<Option<&'a [u8]>, Error> {
+  let mut attributes = Reader::new(der::parse(signed_attributes, TAG_CONTEXT_0)?.content);
+  while !attributes.is_empty() {
+    // Attribute ::= SEQUENCE { attrType OBJECT IDENTIFIER, attrValues SET OF AttributeValue }
+    let mut attribute = Reader::new(attributes.read(TAG_SEQUENCE)?.content);
+    if attribute.read(TAG_OID)?.content == oid {
+      return Ok(Some(attribute.read(TAG_SET)?.content));
+    }
+  }
+  Ok(None)
+}
+
+/// Returns the md_type of the LDSSecurityObject and the hash of a data group
+pub fn data_group_hash(security_object: &[u8], number: u8) -> Result<(u8, &[u8]), Error> {
+  // version, hashAlgorithm, dataGroupHashValues and the optional ldsVersionInfo of version 1
+  let mut fields = Reader::new(der::parse(security_object, TAG_SEQUENCE)?.content);
+  fields.read(TAG_INTEGER)?;
+  let md_type = x509::parse_digest_algorithm(fields.read(TAG_SEQUENCE)?.content)?;
+  let mut hashes = Reader::new(fields.read(TAG_SEQUENCE)?.content);
+  while !hashes.is_empty() {
+    // DataGroupHash ::= SEQUENCE { dataGroupNumber INTEGER, dataGroupHashValue OCTET STRING }
+    let mut hash = Reader::new(hashes.read(TAG_SEQUENCE)?.content);
+    let group = hash.read(TAG_INTEGER)?.content;
+    let value = hash.read(TAG_OCTET_STRING)?.content;
+    if group == [number] {
+      return Ok((md_type, value));
+    }
+  }
+  Err(Error::DataGroupHashMismatch)
+}
@@ -0,0 +1,78 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_std::dynamic_loading::CKBDLContext;
+use ckb_lib_rsa::LibRSA;
+use crate::error::Error;
+use super::der::{self, Reader, TAG_INTEGER, TAG_SEQUENCE};
+use super::x509::{PublicKey, SignatureAlgorithm};
+
+// common header of validate_signature_rsa, as in ckb-passport-lock
+pub const RSA_ALGORITHM_ID: u8 = 1;
+pub const ISO9796_2_ALGORITHM_ID: u8 = 2;
+pub const ECDSA_ALGORITHM_ID: u8 = 16;
+const PKCS1_V15_PADDING: u8 = 0;
+const PKCS1_PSS_PADDING: u8 = 1;
+
+/// Key sizes understood by validate_signature_rsa as (key_size id, N length in bytes), 3072-bit
+/// keys, which some CSCAs use, cannot be verified
+pub const RSA_KEY_SIZES: [(u8, usize); 3] = [(1, 128), (2, 256), (3, 512)];
+
+pub fn rsa_key_size(n: &[u8]) -> Result<u8, Error> {
+  RSA_KEY_SIZES
+    .iter()
+    .find(|(_, len)| *len == n.len())
+    .map(|(id, _)| *id)
+    .ok_or(Error::UnsupportedKeySize)
+}
+
+/// Verifies a signature of message with a certificate key, the md_type is that of the
+/// algorithm or of the context. A signature which does not verify is the error given.
+pub fn verify_signature(
+  key: &PublicKey,
+  algorithm: &SignatureAlgorithm,
+  md_type: u8,
+  message: &[u8],
+  signature: &[u8],
+  error: Error,
+) -> Result<(), Error> {
+  match (key, algorithm) {
+    (PublicKey::Rsa { n, e }, SignatureAlgorithm::Rsa { pss, .. }) => {
+      let key_size = rsa_key_size(n)?;
+      let key_len = n.len();
+      if signature.len() > key_len {
+        return Err(error);
+      }
+      let padding = if *pss { PKCS1_PSS_PADDING } else { PKCS1_V15_PADDING };
+
+      // header || E || N little endian || signature, see rsa.rs of ckb-passport-lock
+      let mut rsa_info = Vec::with_capacity(8 + key_len * 2);
+      rsa_info.extend_from_slice(&[RSA_ALGORITHM_ID, key_size, padding, md_type]);
+      rsa_info.extend_from_slice(&e.to_le_bytes());
+      rsa_info.extend(n.iter().rev());
+      rsa_info.resize(rsa_info.len() + key_len - signature.len(), 0);
+      rsa_info.extend_from_slice(signature);
+
+      let mut context = unsafe { CKBDLContext::<[u8; 1024 * 128]>::new() };
+      let lib = LibRSA::load(&mut context);
+      lib.validate_signature(&rsa_info, message).map_err(|_| error)
+    }
+    (PublicKey::Ecdsa { curve, point }, SignatureAlgorithm::Ecdsa { .. }) => {
+      let key = ckb_lib_ecdsa::PublicKey::from_compressed(*curve, point).map_err(|_| Error::Asn1Encoding)?;
+      let field_len = ckb_lib_ecdsa::get_field_len(*curve).ok_or(Error::UnsupportedCurve)?;
+
+      // Ecdsa-Sig-Value ::= SEQUENCE { r INTEGER, s INTEGER }, to the plain r || s
+      let mut values = Reader::new(der::parse(signature, TAG_SEQUENCE)?.content);
+      let mut plain = Vec::with_capacity(field_len * 2);
+      for _ in 0..2 {
+        let value = der::unsigned_integer(values.read(TAG_INTEGER)?.content)?;
+        if value.len() > field_len {
+          return Err(error);
+        }
+        plain.resize(plain.len() + field_len - value.len(), 0);
+        plain.extend_from_slice(value);
+      }
+      key.verify(md_type, message, &plain).map_err(|_| error)
+    }
+    _ => Err(Error::UnsupportedAlgorithm),
+  }
+}
@@ -0,0 +1,180 @@
+use core::result::Result;
+use alloc::vec::Vec;
+use ckb_lib_ecdsa::{
+  CURVE_BRAINPOOL_P256R1, CURVE_BRAINPOOL_P384R1, CURVE_P256, CURVE_P384, MD_SHA1, MD_SHA224, MD_SHA256,
+  MD_SHA384, MD_SHA512,
+};
+use crate::error::Error;
+use super::der::{self, Reader, TAG_BIT_STRING, TAG_CONTEXT_0, TAG_INTEGER, TAG_OID, TAG_SEQUENCE};
+
+// Contents of the DER encoded object identifiers
+const OID_RSA_ENCRYPTION: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01];
+const OID_RSASSA_PSS: &[u8] = &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0A];
+const OID_EC_PUBLIC_KEY: &[u8] = &[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01];
+
+/// sha*WithRSAEncryption as (oid, md_type)
+const RSA_SIGNATURES: [(&[u8], u8); 5] = [
+  (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x05], MD_SHA1),
+  (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0E], MD_SHA224),
+  (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0B], MD_SHA256),
+  (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0C], MD_SHA384),
+  (&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0D], MD_SHA512),
+];
+
+/// ecdsa-with-SHA* as (oid, md_type)
+const ECDSA_SIGNATURES: [(&[u8], u8); 5] = [
+  (&[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x01], MD_SHA1),
+  (&[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x01], MD_SHA224),
+  (&[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x02], MD_SHA256),
+  (&[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x03], MD_SHA384),
+  (&[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x04, 0x03, 0x04], MD_SHA512),
+];
+
+/// Digest algorithms as (oid, md_type)
+const DIGESTS: [(&[u8], u8); 5] = [
+  (&[0x2B, 0x0E, 0x03, 0x02, 0x1A], MD_SHA1),
+  (&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x04], MD_SHA224),
+  (&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01], MD_SHA256),
+  (&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x02], MD_SHA384),
+  (&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x03], MD_SHA512),
+];
+
+/// Named curves as (oid, curve id of ckb-lib-ecdsa). Explicit curve parameters, which some
+/// countries use, are not supported.
+const CURVES: [(&[u8], u8); 4] = [
+  (&[0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x03, 0x01, 0x07], CURVE_P256),
+  (&[0x2B, 0x81, 0x04, 0x00, 0x22], CURVE_P384),
+  (&[0x2B, 0x24, 0x03, 0x03, 0x02, 0x08, 0x01, 0x01, 0x07], CURVE_BRAINPOOL_P256R1),
+  (&[0x2B, 0x24, 0x03, 0x03, 0x02, 0x08, 0x01, 0x01, 0x0B], CURVE_BRAINPOOL_P384R1),
+];
+
+const EC_POINT_UNCOMPRESSED: u8 = 0x04;
+const EC_POINT_COMPRESSED_EVEN: u8 = 0x02;
+const EC_POINT_COMPRESSED_ODD: u8 = 0x03;
+
+pub enum PublicKey<'a> {
+  /// Big endian modulus without leading zeros and public exponent
+  Rsa { n: &'a [u8], e: u32 },
+  /// SEC1 compressed point
+  Ecdsa { curve: u8, point: Vec<u8> },
+}
+
+pub enum SignatureAlgorithm {
+  /// A plain rsaEncryption signature algorithm leaves the digest to the context, as the
+  /// digestAlgorithm of a CMS SignerInfo
+  Rsa { md_type: Option<u8>, pss: bool },
+  Ecdsa { md_type: u8 },
+}
+
+impl SignatureAlgorithm {
+  pub fn md_type(&self) -> Option<u8> {
+    match self {
+      SignatureAlgorithm::Rsa { md_type, .. } => *md_type,
+      SignatureAlgorithm::Ecdsa { md_type } => Some(*md_type),
+    }
+  }
+}
+
+pub struct Certificate<'a> {
+  /// Encoding of the signed TBSCertificate
+  pub tbs: &'a [u8],
+  pub public_key: PublicKey<'a>,
+  pub algorithm: SignatureAlgorithm,
+  pub signature: &'a [u8],
+}
+
+fn find_oid(table: &[(&[u8], u8)], oid: &[u8]) -> Option<u8> {
+  table.iter().find(|(entry, _)| *entry == oid).map(|(_, id)| *id)
+}
+
+/// Parses the content of a digest AlgorithmIdentifier, whose parameters are ignored
+pub fn parse_digest_algorithm(content: &[u8]) -> Result<u8, Error> {
+  let oid = Reader::new(content).read(TAG_OID)?;
+  find_oid(&DIGESTS, oid.content).ok_or(Error::UnsupportedDigest)
+}
+
+/// Parses the content of a signature AlgorithmIdentifier
+pub fn parse_signature_algorithm(content: &[u8]) -> Result<SignatureAlgorithm, Error> {
+  let mut reader = Reader::new(content);
+  let oid = reader.read(TAG_OID)?.content;
+  if oid == OID_RSA_ENCRYPTION {
+    return Ok(SignatureAlgorithm::Rsa { md_type: None, pss: false });
+  }
+  if oid == OID_RSASSA_PSS {
+    // RSASSA-PSS-params, whose hashAlgorithm [0] defaults to SHA-1. The mask generation
+    // function is taken to be MGF1 with the same digest and the salt as long as the digest,
+    // as validate_signature_rsa requires.
+    let params = reader.read(TAG_SEQUENCE)?;
+    let md_type = match Reader::new(params.content).read_optional(TAG_CONTEXT_0)? {
+      Some(hash) => parse_digest_algorithm(Reader::new(hash.content).read(TAG_SEQUENCE)?.content)?,
+      None => MD_SHA1,
+    };
+    return Ok(SignatureAlgorithm::Rsa { md_type: Some(md_type), pss: true });
+  }
+  if let Some(md_type) = find_oid(&RSA_SIGNATURES, oid) {
+    return Ok(SignatureAlgorithm::Rsa { md_type: Some(md_type), pss: false });
+  }
+  if let Some(md_type) = find_oid(&ECDSA_SIGNATURES, oid) {
+    return Ok(SignatureAlgorithm::Ecdsa { md_type });
+  }
+  Err(Error::UnsupportedAlgorithm)
+}
+
+/// Parses a SubjectPublicKeyInfo
+pub fn parse_public_key(spki: &[u8]) -> Result<PublicKey, Error> {
+  let mut reader = Reader::new(der::parse(spki, TAG_SEQUENCE)?.content);
+  let mut algorithm = Reader::new(reader.read(TAG_SEQUENCE)?.content);
+  let key = der::bit_string(reader.read(TAG_BIT_STRING)?.content)?;
+  let oid = algorithm.read(TAG_OID)?.content;
+
+  if oid == OID_RSA_ENCRYPTION {
+    // RSAPublicKey ::= SEQUENCE { modulus INTEGER, publicExponent INTEGER }
+    let mut fields = Reader::new(der::parse(key, TAG_SEQUENCE)?.content);
+    let n = der::unsigned_integer(fields.read(TAG_INTEGER)?.content)?;
+    let e = der::unsigned_integer(fields.read(TAG_INTEGER)?.content)?;
+    if e.len() > 4 {
+      return Err(Error::UnsupportedKeySize);
+    }
+    let e = e.iter().fold(0u32, |e, byte| (e << 8) | *byte as u32);
+    return Ok(PublicKey::Rsa { n, e });
+  }
+
+  if oid == OID_EC_PUBLIC_KEY {
+    let curve_oid = algorithm.read(TAG_OID).map_err(|_| Error::UnsupportedCurve)?;
+    let curve = find_oid(&CURVES, curve_oid.content).ok_or(Error::UnsupportedCurve)?;
+    let field_len = ckb_lib_ecdsa::get_field_len(curve).ok_or(Error::UnsupportedCurve)?;
+    let point = match key.first() {
+      Some(&EC_POINT_UNCOMPRESSED) if key.len() == 1 + field_len * 2 => {
+        let mut point = Vec::with_capacity(1 + field_len);
+        point.push(EC_POINT_COMPRESSED_EVEN | (key[field_len * 2] & 1));
+        point.extend_from_slice(&key[1..(1 + field_len)]);
+        point
+      }
+      Some(&EC_POINT_COMPRESSED_EVEN) | Some(&EC_POINT_COMPRESSED_ODD) if key.len() == 1 + field_len => key.to_vec(),
+      _ => return Err(Error::Asn1Encoding),
+    };
+    return Ok(PublicKey::Ecdsa { curve, point });
+  }
+
+  Err(Error::UnsupportedAlgorithm)
+}
+
+/// Parses a Certificate. Its validity period is not checked, a script cannot tell the
+/// current time and a passport remains genuine after its document signer certificate expires.
+pub fn parse_certificate(encoding: &[u8]) -> Result<Certificate, Error> {
+  let mut reader = Reader::new(der::parse(encoding, TAG_SEQUENCE)?.content);
+  let tbs = reader.read(TAG_SEQUENCE)?;
+  let algorithm = parse_signature_algorithm(reader.read(TAG_SEQUENCE)?.content)?;
+  let signature = der::bit_string(reader.read(TAG_BIT_STRING)?.content)?;
+
+  let mut fields = Reader::new(tbs.content);
+  // version, serialNumber, signature, issuer, validity and subject
+  fields.read_optional(TAG_CONTEXT_0)?;
+  fields.read(TAG_INTEGER)?;
+  for _ in 0..4 {
+    fields.read(TAG_SEQUENCE)?;
+  }
+  let public_key = parse_public_key(fields.read(TAG_SEQUENCE)?.encoding)?;
+
+  Ok(Certificate { tbs: tbs.encoding, public_key, algorithm, signature })
+}
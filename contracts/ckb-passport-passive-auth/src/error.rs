@@ -0,0 +1,39 @@
+use ckb_std::error::SysError;
+
+/// Error
+#[repr(i8)]
+pub enum Error {
+    IndexOutOfBound = 1,
+    ItemMissing,
+    LengthNotEnough,
+    Encoding,
+    InvalidArgument = 5,
+    // Add customized errors here...
+    MasterListMissing,
+    CscaMissing,
+    Asn1Encoding,
+    UnsupportedAlgorithm,
+    UnsupportedKeySize = 10,
+    UnsupportedCurve,
+    UnsupportedDigest,
+    CertificateVerifyError,
+    SignerVerifyError,
+    MessageDigestMismatch = 15,
+    DataGroupHashMismatch,
+    AaKeyMismatch,
+    SecurityObjectMismatch,
+    ContentTypeMismatch,
+}
+
+impl From<SysError> for Error {
+    fn from(err: SysError) -> Self {
+        use SysError::*;
+        match err {
+            IndexOutOfBound => Self::IndexOutOfBound,
+            ItemMissing => Self::ItemMissing,
+            LengthNotEnough(_) => Self::LengthNotEnough,
+            Encoding => Self::Encoding,
+            Unknown(err_code) => panic!("unexpected sys error {}", err_code),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// Import from `core` instead of from `std` since we are in no-std mode
+use core::result::Result;
+
+use blake2b_ref::Blake2bBuilder;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, prelude::*},
+    high_level::{load_cell_data, load_script, QueryIter},
+};
+use crate::error::Error;
+
+const CKB_HASH_PERSONALIZATION: &[u8] = b"ckb-default-hash";
+const PUB_KEY_HASH_LEN: usize = 20;
+const COMMON_HEADER: usize = 4;
+const PUBLIC_KEY_E_LEN: usize = 4;
+
+const RSA_ALGORITHM_ID: u8 = 1;
+const ISO9796_2_ALGORITHM_ID: u8 = 2;
+const ECDSA_ALGORITHM_ID: u8 = 16;
+
+/// Lengths of N by key size id, as in ckb-passport-lock
+const RSA_KEY_SIZES: [(u8, usize); 3] = [(1, 128), (2, 256), (3, 512)];
+
+/// Type script of the public key registry cells of ckb-passport-lock. The data of a registry
+/// cell are the common header and the public key of a passport, as a witness lock carries
+/// them, and the args are the blake160 of the data, which is the pubkey hash of the lock args.
+///
+/// Every created cell must hold a well formed key of its args, so a registry cell can neither
+/// be created with a malformed key nor be updated to another key.
+pub fn main() -> Result<(), Error> {
+    let script = load_script()?;
+    let args: Bytes = script.args().unpack();
+    if args.len() != PUB_KEY_HASH_LEN {
+        return Err(Error::InvalidArgument);
+    }
+
+    for data in QueryIter::new(load_cell_data, Source::GroupOutput) {
+        check_pub_key(&data)?;
+        if blake2b_160(&data)[..] != args[..] {
+            return Err(Error::PubKeyHashMismatch);
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the public key has the length its header requires, E || N for RSA and a
+/// compressed point on the curve for ECDSA
+fn check_pub_key(data: &[u8]) -> Result<(), Error> {
+    if data.len() < COMMON_HEADER {
+        return Err(Error::Encoding);
+    }
+    let (header, pub_key) = data.split_at(COMMON_HEADER);
+    let key_size = header[1];
+    match header[0] {
+        RSA_ALGORITHM_ID | ISO9796_2_ALGORITHM_ID => {
+            let n_len = RSA_KEY_SIZES
+                .iter()
+                .find(|(id, _)| *id == key_size)
+                .map(|(_, n_len)| *n_len)
+                .ok_or(Error::UnsupportedKeySize)?;
+            if pub_key.len() != PUBLIC_KEY_E_LEN + n_len {
+                return Err(Error::InvalidPubKey);
+            }
+        }
+        ECDSA_ALGORITHM_ID => {
+            ckb_lib_ecdsa::PublicKey::from_compressed(key_size, pub_key).map_err(|err| match err {
+                ckb_lib_ecdsa::Error::UnsupportedCurve => Error::UnsupportedKeySize,
+                _ => Error::InvalidPubKey,
+            })?;
+        }
+        _ => return Err(Error::UnsupportedAlgorithm),
+    }
+    Ok(())
+}
+
+fn blake2b_160(data: &[u8]) -> [u8; PUB_KEY_HASH_LEN] {
+    let mut hash = [0u8; 32];
+    let mut blake2b = Blake2bBuilder::new(32).personal(CKB_HASH_PERSONALIZATION).build();
+    blake2b.update(data);
+    blake2b.finalize(&mut hash);
+    let mut result = [0u8; PUB_KEY_HASH_LEN];
+    result.copy_from_slice(&hash[..PUB_KEY_HASH_LEN]);
+    result
+}
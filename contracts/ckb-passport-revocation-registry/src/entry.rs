@@ -0,0 +1,52 @@
+// Import from `core` instead of from `std` since we are in no-std mode
+use core::result::Result;
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::{bytes::Bytes, prelude::*},
+    high_level::{load_cell_data, load_cell_lock_hash, load_script, QueryIter},
+};
+use crate::error::Error;
+
+const LOCK_HASH_LEN: usize = 32;
+const PUB_KEY_HASH_LEN: usize = 20;
+
+/// Type script of the revocation registry cells of ckb-passport-lock, which the lock args pin
+/// by their type script hash. The args are the lock hash of the updater of the registry and
+/// the data are the revoked pubkey hashes in strictly ascending order, as the lock looks up a
+/// signer by its position among them.
+///
+/// Only a transaction with an input of the updater lock creates, updates or destroys registry
+/// cells, and every created cell must hold a strictly ascending list.
+pub fn main() -> Result<(), Error> {
+    let script = load_script()?;
+    let args: Bytes = script.args().unpack();
+    if args.len() != LOCK_HASH_LEN {
+        return Err(Error::InvalidArgument);
+    }
+
+    let updater_present =
+        QueryIter::new(load_cell_lock_hash, Source::Input).any(|lock_hash| lock_hash[..] == args[..]);
+    if !updater_present {
+        return Err(Error::UpdaterNotPresent);
+    }
+    for data in QueryIter::new(load_cell_data, Source::GroupOutput) {
+        check_revoked(&data)?;
+    }
+    Ok(())
+}
+
+/// Checks that the data are pubkey hashes in strictly ascending order
+fn check_revoked(data: &[u8]) -> Result<(), Error> {
+    if data.len() % PUB_KEY_HASH_LEN != 0 {
+        return Err(Error::Encoding);
+    }
+    let mut previous: Option<&[u8]> = None;
+    for pub_key_hash in data.chunks(PUB_KEY_HASH_LEN) {
+        if previous.map_or(false, |previous| previous >= pub_key_hash) {
+            return Err(Error::RevokedNotSorted);
+        }
+        previous = Some(pub_key_hash);
+    }
+    Ok(())
+}
@@ -1,35 +1,171 @@
 use super::*;
 
+use ckb_testtool::builtin::ALWAYS_SUCCESS;
 use ckb_testtool::context::Context;
+use ckb_tool::ckb_crypto::secp::{Generator, Privkey};
 use ckb_tool::ckb_hash::{new_blake2b, blake2b_256};
 use ckb_tool::ckb_types::{
     bytes::Bytes,
-    core::{TransactionBuilder, TransactionView},
+    core::{EpochNumberWithFraction, HeaderBuilder, ScriptHashType, TransactionBuilder, TransactionView},
     packed::{self, *},
     prelude::*,
 };
 use ckb_tool::ckb_error::assert_error_eq;
-use ckb_tool::ckb_script::ScriptError;
-use openssl::hash::MessageDigest;
+use ckb_tool::ckb_script::{ScriptError, TransactionScriptError};
+use ckb_passport_lock_sdk::{args::LockArgs, delegation, document, passive_auth, revocation, rotation, signer, whitelist};
+use ckb_passport_lock_types as passport_types;
+use openssl::asn1::Asn1Time;
+use openssl::bn::{BigNum, BigNumContext};
+use openssl::ec::{EcGroup, EcKey, PointConversionForm};
+use openssl::ecdsa::EcdsaSig;
+use openssl::hash::{hash, Hasher, MessageDigest};
+use openssl::nid::Nid;
 use openssl::pkey::{PKey, Private, Public};
-use openssl::rsa::Rsa;
-use openssl::sign::{Signer, Verifier};
+use openssl::rand::rand_bytes;
+use openssl::rsa::{Padding, Rsa};
+use openssl::sign::{RsaPssSaltlen, Signer};
+use openssl::x509::{X509Builder, X509NameBuilder};
 use std::fs;
 
 const MAX_CYCLES: u64 = 70_000_000;
 
+const INVALID_ARGUMENT: i8 = 5;
 const ERROR_ISO97962_INVALID_ARG9: i8 = 17;
 const WRONG_PUB_KEY: i8 = 6;
+const RSA_PUB_KEY_SIG_LENGTH_ERROR: i8 = 7;
+const UNSUPPORTED_KEY_SIZE: i8 = 19;
+const UNSUPPORTED_DIGEST: i8 = 20;
+const DIGEST_TOO_WEAK: i8 = 21;
+const UNSUPPORTED_CURVE: i8 = 22;
+const ECDSA_VERIFY_ERROR: i8 = 26;
+const UNSUPPORTED_PADDING: i8 = 28;
+const RSA_VERIFY_ERROR: i8 = 29;
+const UNSUPPORTED_CHUNK_SIZE: i8 = 30;
+const UNSUPPORTED_WITNESS_VERSION: i8 = 31;
+const WITNESS_TOO_SHORT: i8 = 32;
+const WITNESS_TRAILING_BYTES: i8 = 33;
+const UNSUPPORTED_ARGS_FLAGS: i8 = 35;
+const MULTISIG_THRESHOLD_NOT_MET: i8 = 37;
+const DUPLICATE_SIGNER: i8 = 38;
+const SINCE_NOT_SATISFIED: i8 = 40;
+const GUARDIANS_NOT_PRESENT: i8 = 42;
+const RECOVERY_OUTPUT_MISMATCH: i8 = 43;
+const SINCE_MISMATCH: i8 = 44;
+const BENEFICIARY_NOT_PRESENT: i8 = 45;
+const GRACE_CLAIM_OUTPUT_MISMATCH: i8 = 46;
+const PAYMENT_OUTPUT_MISMATCH: i8 = 47;
+const PAYMENT_TOO_SMALL: i8 = 48;
+const UNSUPPORTED_SIGHASH_TYPE: i8 = 49;
+const SIGHASH_OUTPUT_MISSING: i8 = 50;
+const SPENDING_LIMIT_EXCEEDED: i8 = 51;
+const OUTPUT_NOT_WHITELISTED: i8 = 52;
+const VAULT_OUTPUT_MISMATCH: i8 = 53;
+const REVOCATION_REGISTRY_MISSING: i8 = 54;
+const PASSPORT_REVOKED: i8 = 55;
+const DELEGATION_MISSING: i8 = 56;
+const DELEGATION_CAP_EXCEEDED: i8 = 58;
+const DELEGATION_OUTPUT_MISMATCH: i8 = 59;
+const PASSPORT_NOT_REGISTERED: i8 = 60;
+const DOCUMENT_MISMATCH: i8 = 61;
+const SPENDING_STATE_MISMATCH: i8 = 62;
 
-const MESSAGE_SINGLE_SIZE: usize = 8;
-const SUB_SIGNATURE_SIZE: usize = 128;
-const TX_SIGNATURE_SIZE: usize = 512;
-const SIGN_INFO_SIZE: usize = 648;  // 512 + 136
+// errors of the pubkey registry type script
+const REGISTRY_INVALID_PUB_KEY: i8 = 8;
+const REGISTRY_PUB_KEY_HASH_MISMATCH: i8 = 9;
 
+// errors of the revocation registry type script
+const REVOCATION_ENCODING: i8 = 4;
+const REVOCATION_UPDATER_NOT_PRESENT: i8 = 6;
+const REVOCATION_REVOKED_NOT_SORTED: i8 = 7;
+
+// errors of the passive authentication type script
+const PASSIVE_AUTH_CERTIFICATE_VERIFY_ERROR: i8 = 13;
+const PASSIVE_AUTH_DATA_GROUP_HASH_MISMATCH: i8 = 16;
+const PASSIVE_AUTH_AA_KEY_MISMATCH: i8 = 17;
+const PASSIVE_AUTH_SECURITY_OBJECT_MISMATCH: i8 = 18;
+const PASSIVE_AUTH_CONTENT_TYPE_MISMATCH: i8 = 19;
+
+const FLAG_MULTISIG: u8 = 1;
+const FLAG_BACKUP_KEY: u8 = 1 << 1;
+
+const FLAG_GUARDIANS: u8 = 1 << 2;
+
+const FLAG_SINCE: u8 = 1 << 3;
+
+const FLAG_INHERITANCE: u8 = 1 << 4;
+
+const FLAG_ROTATION_GRACE: u8 = 1 << 5;
+
+const FLAG_ACP: u8 = 1 << 6;
+
+// the second flags byte, after a first one with the continuation bit only
+const FLAG_TX_SPENDING_LIMIT: u8 = 1;
+const FLAG_WHITELIST: u8 = 1 << 1;
+const FLAG_VAULT: u8 = 1 << 2;
+const FLAG_UNVAULTING: u8 = 1 << 3;
+const FLAG_REVOCATION: u8 = 1 << 4;
+const FLAG_DELEGATION: u8 = 1 << 5;
+const FLAG_PASSIVE_AUTH: u8 = 1 << 6;
+
+// the third flags byte
+const FLAG_DOCUMENT: u8 = 1;
+const FLAG_DOCUMENT_REVEAL: u8 = 1 << 1;
+const FLAG_RECOVERY_PENDING: u8 = 1 << 2;
+const FLAG_GRACE_CLAIMED: u8 = 1 << 3;
+const FLAG_EPOCH_SPENDING_LIMIT: u8 = 1 << 4;
+const FLAG_MIN_DIGEST: u8 = 1 << 5;
+
+const SECP256K1_SIGNATURE_SIZE: usize = 65;
+// relative since of 10 blocks
+const RECOVERY_DELAY: u64 = 0x8000_0000_0000_000A;
+// relative since of 100 blocks
+const INHERITANCE_PERIOD: u64 = 0x8000_0000_0000_0064;
+// absolute since of the timestamp 1893456000 (2030-01-01)
+const PASSPORT_EXPIRY: u64 = 0x4000_0000_70DB_D880;
+// relative since of 1000 blocks
+const GRACE_CLAIM_DELAY: u64 = 0x8000_0000_0000_03E8;
+const TX_SPENDING_LIMIT: u64 = 500;
+// relative since of 100 blocks
+const TX_SPENDING_LIMIT_DELAY: u64 = 0x8000_0000_0000_0064;
+// epoch of the block of the spending state cell
+const SPENDING_STATE_EPOCH: u64 = 100;
+// relative since of 50 blocks
+const UNVAULTING_DELAY: u64 = 0x8000_0000_0000_0032;
+// absolute since of the epoch 100
+const DELEGATION_EXPIRY: u64 = 0x2000_0100_0000_0064;
+
+const SUB_SIGNATURE_COUNT: usize = 4;
+
+// MRZ of the specimen passport of ICAO 9303, as DG1 wraps it
+const DG1: &[u8] = b"\x61\x5B\x5F\x1F\x58P<UTOERIKSSON<<ANNA<MARIA<<<<<<<<<<<<<<<<<<<L898902C36UTO7408122F1204159ZE184226B<<<<<10";
+
+// high nibble of the padding byte
+const CHUNKING_DEFAULT: u8 = 0;
+const CHUNKING_16: u8 = 2 << 4;
+const CHUNKING_32: u8 = 3 << 4;
+const PUBLIC_KEY_E_SIZE: usize = 4;
+
+const RSA_ALGORITHM_ID: u8 = 1;
 const ISO9796_2_ALGORITHM_ID: u8 = 2;
-const ISO9796_2_KEY_SIZE: u8 = 1;  // 1024
 const ISO9796_2_PADDING: u8 = 0;
+const PKCS1_V15_PADDING: u8 = 0;
+const PKCS1_PSS_PADDING: u8 = 1;
 const ISO9796_2_MD_SHA1: u8 = 4;
+const ISO9796_2_MD_SHA224: u8 = 5;
+const ISO9796_2_MD_SHA256: u8 = 6;
+const ISO9796_2_MD_SHA384: u8 = 7;
+const ISO9796_2_MD_SHA512: u8 = 8;
+
+const ECDSA_ALGORITHM_ID: u8 = 16;
+const ECDSA_PADDING: u8 = 0;
+const CURVE_P256: u8 = 1;
+const CURVE_P384: u8 = 2;
+const CURVE_BRAINPOOL_P256R1: u8 = 3;
+const CURVE_BRAINPOOL_P384R1: u8 = 4;
+
+// openssl NID_brainpoolP256r1 and NID_brainpoolP384r1
+const NID_BRAINPOOL_P256R1: i32 = 927;
+const NID_BRAINPOOL_P384R1: i32 = 931;
 
 fn blake160(data: &[u8]) -> [u8; 20] {
     let mut buf = [0u8; 20];
@@ -38,12 +174,182 @@ fn blake160(data: &[u8]) -> [u8; 20] {
     buf
 }
 
+// key_size id of validate_signature_rsa, 1536 and 3072-bit keys have no id
+fn get_key_size(public_key: &PKey<Public>) -> u8 {
+    match public_key.bits() {
+        1024 => 1,
+        2048 => 2,
+        4096 => 3,
+        _ => 0,
+    }
+}
+
+fn get_key_len(public_key: &PKey<Public>) -> usize {
+    public_key.rsa().unwrap().size() as usize
+}
+
+fn get_message_digest(md_type: u8) -> MessageDigest {
+    match md_type {
+        ISO9796_2_MD_SHA1 => MessageDigest::sha1(),
+        ISO9796_2_MD_SHA224 => MessageDigest::sha224(),
+        ISO9796_2_MD_SHA256 => MessageDigest::sha256(),
+        ISO9796_2_MD_SHA384 => MessageDigest::sha384(),
+        ISO9796_2_MD_SHA512 => MessageDigest::sha512(),
+        _ => MessageDigest::md5(),
+    }
+}
+
+// SHA-1 uses the implicit trailer 0xBC, other digests the explicit trailer hash_id || 0xCC
+fn get_iso9796_2_trailer(md_type: u8) -> Vec<u8> {
+    match md_type {
+        ISO9796_2_MD_SHA1 => vec![0xBC],
+        ISO9796_2_MD_SHA224 => vec![0x38, 0xCC],
+        ISO9796_2_MD_SHA256 => vec![0x34, 0xCC],
+        ISO9796_2_MD_SHA384 => vec![0x36, 0xCC],
+        ISO9796_2_MD_SHA512 => vec![0x35, 0xCC],
+        _ => vec![0xCC],
+    }
+}
+
+// ISO 9796-2 scheme 1 with partial message recovery, as done by the chip for active authentication:
+// 0x6A || M1 || Hash(M1 || M2) || trailer, where M2 is the 8 bytes challenge
+fn iso9796_2_sign(private_key: &PKey<Private>, md_type: u8, message: &[u8]) -> Vec<u8> {
+    let rsa = private_key.rsa().unwrap();
+    let key_len = rsa.size() as usize;
+    let message_digest = get_message_digest(md_type);
+    let trailer = get_iso9796_2_trailer(md_type);
+
+    let mut m1 = vec![0u8; key_len - 1 - message_digest.size() - trailer.len()];
+    rand_bytes(&mut m1).unwrap();
+
+    let mut hasher = Hasher::new(message_digest).unwrap();
+    hasher.update(&m1).unwrap();
+    hasher.update(message).unwrap();
+    let digest = hasher.finish().unwrap();
+
+    let mut block = vec![0x6A];
+    block.extend_from_slice(&m1);
+    block.extend_from_slice(&digest);
+    block.extend_from_slice(&trailer);
+
+    let mut signature = vec![0u8; key_len];
+    rsa.private_encrypt(&block, &mut signature, Padding::NONE).unwrap();
+    signature
+}
+
+fn pkcs1_sign(private_key: &PKey<Private>, md_type: u8, message: &[u8]) -> Vec<u8> {
+    let mut signer = Signer::new(get_message_digest(md_type), &private_key).unwrap();
+    signer.update(message).unwrap();
+    signer.sign_to_vec().unwrap()
+}
+
+fn pss_sign(private_key: &PKey<Private>, md_type: u8, message: &[u8]) -> Vec<u8> {
+    let message_digest = get_message_digest(md_type);
+    let mut signer = Signer::new(message_digest, &private_key).unwrap();
+    signer.set_rsa_padding(Padding::PKCS1_PSS).unwrap();
+    signer.set_rsa_pss_saltlen(RsaPssSaltlen::DIGEST_LENGTH).unwrap();
+    signer.set_rsa_mgf1_md(message_digest).unwrap();
+    signer.update(message).unwrap();
+    signer.sign_to_vec().unwrap()
+}
+
+// PKCS#1 signs the whole message at once
+fn sign_tx_pkcs1(
+    tx: TransactionView,
+    private_key: &PKey<Private>,
+    public_key: &PKey<Public>,
+    padding: u8,
+    md_type: u8,
+) -> TransactionView {
+    let header = [RSA_ALGORITHM_ID, get_key_size(public_key), padding, md_type];
+    let (rsa_info, _) = compute_rsa_pub_key_hash(public_key, header, false);
+    sign_tx_with(tx, rsa_info, get_key_len(public_key), 1, |message| {
+        if padding == PKCS1_PSS_PADDING {
+            pss_sign(private_key, md_type, message)
+        } else {
+            pkcs1_sign(private_key, md_type, message)
+        }
+    })
+}
+
 fn sign_tx(
     tx: TransactionView,
     private_key: &PKey<Private>,
     public_key: &PKey<Public>,
-    is_pub_key_hash_error: bool
+    md_type: u8,
+    is_pub_key_hash_error: bool,
+    sign: fn(&PKey<Private>, u8, &[u8]) -> Vec<u8>,
+) -> TransactionView {
+    let (rsa_info, _) = compute_pub_key_hash(public_key, md_type, is_pub_key_hash_error);
+    sign_tx_with(tx, rsa_info, get_key_len(public_key), SUB_SIGNATURE_COUNT, |sub_message| {
+        sign(private_key, md_type, sub_message)
+    })
+}
+
+// The witness lock is sub-signatures || common header || public key, every sub-signature
+// signs a 32 / signature_count bytes chunk of the message
+fn sign_tx_with<F: Fn(&[u8]) -> Vec<u8>>(
+    tx: TransactionView,
+    pub_key_info: Vec<u8>,
+    sub_signature_len: usize,
+    signature_count: usize,
+    sign: F,
+) -> TransactionView {
+    sign_tx_with_lock(tx, sub_signature_len, signature_count, sign, |signatures| {
+        let mut lock = signatures.concat();
+        lock.extend_from_slice(&pub_key_info);
+        lock
+    })
+}
+
+// The witness lock is a PassportLockWitness of the common header, public key and sub-signatures
+fn sign_tx_versioned<F: Fn(&[u8]) -> Vec<u8>>(
+    tx: TransactionView,
+    version: u8,
+    pub_key_info: Vec<u8>,
+    sub_signature_len: usize,
+    signature_count: usize,
+    sign: F,
+) -> TransactionView {
+    sign_tx_with_lock(tx, sub_signature_len, signature_count, sign, |signatures| {
+        let mut header = [0u8; 4];
+        header.copy_from_slice(&pub_key_info[..4]);
+        let signatures = signatures
+            .iter()
+            .map(|signature| passport_types::Bytes::from(&signature[..]))
+            .collect();
+        passport_types::PassportLockWitness::new_builder()
+            .version(version.into())
+            .header(header.into())
+            .pubkey(passport_types::Bytes::from(&pub_key_info[4..]))
+            .signatures(passport_types::BytesVec::new_builder().set(signatures).build())
+            .build()
+            .as_slice()
+            .to_vec()
+    })
+}
+
+fn sign_tx_with_lock<F: Fn(&[u8]) -> Vec<u8>, L: Fn(Vec<Vec<u8>>) -> Vec<u8>>(
+    tx: TransactionView,
+    sub_signature_len: usize,
+    signature_count: usize,
+    sign: F,
+    build_lock: L,
 ) -> TransactionView {
+    sign_tx_message(tx, |message| match message {
+        Some(message) => build_lock(
+            message
+                .chunks(message.len() / signature_count)
+                .map(|sub_message| sign(sub_message))
+                .collect(),
+        ),
+        None => build_lock(vec![vec![0u8; sub_signature_len]; signature_count]),
+    })
+}
+
+// The message is signed over the witness with a zeroed lock of the same length, build_lock
+// returns a lock of that length without a message
+fn sign_tx_message<L: Fn(Option<&[u8]>) -> Vec<u8>>(tx: TransactionView, build_lock: L) -> TransactionView {
     let witnesses_len = tx.witnesses().len();
     let tx_hash = tx.hash();
 
@@ -54,11 +360,7 @@ fn sign_tx(
 
     // digest the first witness
     let witness = WitnessArgs::default();
-    let zero_lock: Bytes = {
-        let mut buf = Vec::new();
-        buf.resize(SIGN_INFO_SIZE, 0);
-        buf.into()
-    };
+    let zero_lock: Bytes = vec![0u8; build_lock(None).len()].into();
     let witness_for_digest = witness
         .clone()
         .as_builder()
@@ -75,29 +377,12 @@ fn sign_tx(
     });
     blake2b.finalize(&mut message);
 
-    // openssl
-    let mut rsa_signature = [0u8; TX_SIGNATURE_SIZE];
-    for index in 0..4 {
-        let mut signer = Signer::new(MessageDigest::sha1(), &private_key).unwrap();
-        signer.update(&message[MESSAGE_SINGLE_SIZE * index..MESSAGE_SINGLE_SIZE * (index + 1)]).unwrap();
-        rsa_signature[SUB_SIGNATURE_SIZE * index..SUB_SIGNATURE_SIZE * (index + 1)].copy_from_slice(&signer.sign_to_vec().unwrap());
-    }
-
-    let mut signed_signature = rsa_signature.clone().to_vec();
-    let (mut rsa_info, _) = compute_pub_key_hash(public_key, is_pub_key_hash_error);
-    signed_signature.append(&mut rsa_info);
-
-    // verify it locally
-    for index in 0..4 {
-        let mut verifier = Verifier::new(MessageDigest::sha1(), &public_key).unwrap();
-        verifier.update(&message[MESSAGE_SINGLE_SIZE * index..MESSAGE_SINGLE_SIZE * (index + 1)]).unwrap();
-        assert!(verifier.verify(&rsa_signature[SUB_SIGNATURE_SIZE * index..SUB_SIGNATURE_SIZE * (index + 1)]).unwrap());
-    }
+    let signed_lock = build_lock(Some(&message));
 
     signed_witnesses.push(
         witness
             .as_builder()
-            .lock(Some(Bytes::from(signed_signature)).pack())
+            .lock(Some(Bytes::from(signed_lock)).pack())
             .build()
             .as_bytes()
             .pack(),
@@ -110,9 +395,96 @@ fn sign_tx(
         .build()
 }
 
-fn compute_pub_key_hash(public_key: &PKey<Public>, is_pub_key_hash_error: bool) -> (Vec<u8>, Vec<u8>) {
+// The witness lock is the recoverable secp256k1 signature of the message
+fn sign_tx_secp256k1(tx: TransactionView, private_key: &Privkey) -> TransactionView {
+    sign_tx_message(tx, |message| match message {
+        Some(message) => {
+            let mut message_hash = [0u8; 32];
+            message_hash.copy_from_slice(message);
+            private_key
+                .sign_recoverable(&message_hash.into())
+                .unwrap()
+                .serialize()
+        }
+        None => vec![0u8; SECP256K1_SIGNATURE_SIZE],
+    })
+}
+
+fn compute_secp256k1_pub_key_hash(private_key: &Privkey) -> Vec<u8> {
+    blake160(&private_key.pubkey().unwrap().serialize()).into()
+}
+
+fn compute_multisig_hash(threshold: u8, pub_key_hashes: &[Vec<u8>]) -> Vec<u8> {
+    let mut config = vec![threshold, pub_key_hashes.len() as u8];
+    let mut sorted_hashes = pub_key_hashes.to_vec();
+    sorted_hashes.sort();
+    config.append(&mut sorted_hashes.concat());
+    blake160(&config).into()
+}
+
+// The witness lock is a MultisigWitness, every signer signs the whole message with ISO 9796-2
+fn sign_tx_multisig(
+    tx: TransactionView,
+    threshold: u8,
+    pub_key_hashes: &[Vec<u8>],
+    signers: &[(PKey<Private>, PKey<Public>)],
+) -> TransactionView {
+    let mut sorted_hashes = pub_key_hashes.to_vec();
+    sorted_hashes.sort();
+    let pub_key_hashes = sorted_hashes
+        .iter()
+        .map(|pub_key_hash| {
+            let mut hash = [0u8; 20];
+            hash.copy_from_slice(pub_key_hash);
+            hash.into()
+        })
+        .collect();
+    let pub_key_hashes = passport_types::Byte20Vec::new_builder().set(pub_key_hashes).build();
+
+    sign_tx_message(tx, |message| {
+        let signers = signers
+            .iter()
+            .map(|(private_key, public_key)| {
+                let (rsa_info, _) = compute_pub_key_hash(public_key, ISO9796_2_MD_SHA1, false);
+                let signatures = match message {
+                    Some(message) => message
+                        .chunks(message.len() / SUB_SIGNATURE_COUNT)
+                        .map(|sub_message| iso9796_2_sign(private_key, ISO9796_2_MD_SHA1, sub_message))
+                        .collect(),
+                    None => vec![vec![0u8; get_key_len(public_key)]; SUB_SIGNATURE_COUNT],
+                };
+                let mut header = [0u8; 4];
+                header.copy_from_slice(&rsa_info[..4]);
+                let signatures = signatures
+                    .iter()
+                    .map(|signature: &Vec<u8>| passport_types::Bytes::from(&signature[..]))
+                    .collect();
+                passport_types::PassportSigner::new_builder()
+                    .header(header.into())
+                    .pubkey(passport_types::Bytes::from(&rsa_info[4..]))
+                    .signatures(passport_types::BytesVec::new_builder().set(signatures).build())
+                    .build()
+            })
+            .collect();
+        passport_types::MultisigWitness::new_builder()
+            .version(1.into())
+            .threshold(threshold.into())
+            .pubkey_hashes(pub_key_hashes.clone())
+            .signers(passport_types::PassportSignerVec::new_builder().set(signers).build())
+            .build()
+            .as_slice()
+            .to_vec()
+    })
+}
+
+fn compute_pub_key_hash(public_key: &PKey<Public>, md_type: u8, is_pub_key_hash_error: bool) -> (Vec<u8>, Vec<u8>) {
+    let header = [ISO9796_2_ALGORITHM_ID, get_key_size(public_key), ISO9796_2_PADDING, md_type];
+    compute_rsa_pub_key_hash(public_key, header, is_pub_key_hash_error)
+}
+
+fn compute_rsa_pub_key_hash(public_key: &PKey<Public>, header: [u8; 4], is_pub_key_hash_error: bool) -> (Vec<u8>, Vec<u8>) {
     let mut result: Vec<u8> = vec![];
-    result.extend_from_slice(&[ISO9796_2_ALGORITHM_ID, ISO9796_2_KEY_SIZE, ISO9796_2_PADDING, ISO9796_2_MD_SHA1]);
+    result.extend_from_slice(&header);
 
     let rsa_public_key = public_key.rsa().unwrap();
 
@@ -127,10 +499,10 @@ fn compute_pub_key_hash(public_key: &PKey<Public>, is_pub_key_hash_error: bool)
     e.reverse();
     n.reverse();
 
-    while e.len() < 4 {
+    while e.len() < PUBLIC_KEY_E_SIZE {
         e.push(0);
     }
-    while n.len() < 128 {
+    while n.len() < get_key_len(public_key) {
         n.push(0);
     }
 
@@ -141,8 +513,8 @@ fn compute_pub_key_hash(public_key: &PKey<Public>, is_pub_key_hash_error: bool)
     (result, h)
 }
 
-fn generate_random_key() -> (PKey<Private>, PKey<Public>) {
-    let rsa = Rsa::generate(1024).unwrap();
+fn generate_random_key(bits: u32) -> (PKey<Private>, PKey<Public>) {
+    let rsa = Rsa::generate(bits).unwrap();
     let private_key = PKey::from_rsa(rsa).unwrap();
 
     let public_key_pem: Vec<u8> = private_key.public_key_to_pem().unwrap();
@@ -150,12 +522,70 @@ fn generate_random_key() -> (PKey<Private>, PKey<Public>) {
     (private_key, public_key)
 }
 
-#[test]
-fn test_wrong_signature() {
-    let (private_key, public_key) = generate_random_key();
+fn get_signature_count(chunking: u8, default_count: usize) -> usize {
+    match chunking {
+        CHUNKING_DEFAULT => default_count,
+        CHUNKING_16 => 2,
+        CHUNKING_32 => 1,
+        _ => 4,
+    }
+}
+
+fn generate_random_ec_key(curve: Nid) -> EcKey<Private> {
+    let group = EcGroup::from_curve_name(curve).unwrap();
+    EcKey::generate(&group).unwrap()
+}
+
+fn get_curve_field_len(private_key: &EcKey<Private>) -> usize {
+    ((private_key.group().degree() + 7) / 8) as usize
+}
+
+// plain r || s signature as returned by the chip
+fn ecdsa_sign(private_key: &EcKey<Private>, md_type: u8, message: &[u8]) -> Vec<u8> {
+    let field_len = get_curve_field_len(private_key);
+    let digest = hash(get_message_digest(md_type), message).unwrap();
+    let signature = EcdsaSig::sign(&digest, private_key).unwrap();
+    let mut result = vec![0u8; field_len * 2];
+    let r = signature.r().to_vec();
+    let s = signature.s().to_vec();
+    result[(field_len - r.len())..field_len].copy_from_slice(&r);
+    result[(field_len * 2 - s.len())..].copy_from_slice(&s);
+    result
+}
+
+fn compute_ec_pub_key_hash(private_key: &EcKey<Private>, curve_id: u8, chunking: u8, md_type: u8) -> (Vec<u8>, Vec<u8>) {
+    let mut result: Vec<u8> = vec![];
+    result.extend_from_slice(&[ECDSA_ALGORITHM_ID, curve_id, ECDSA_PADDING | chunking, md_type]);
+
+    let group = private_key.group();
+    let mut ctx = BigNumContext::new().unwrap();
+    let mut point = private_key
+        .public_key()
+        .to_bytes(group, PointConversionForm::COMPRESSED, &mut ctx)
+        .unwrap();
+    result.append(&mut point);
+
+    let h = blake160(&result).into();
+    (result, h)
+}
+
+fn sign_tx_ecdsa(
+    tx: TransactionView,
+    private_key: &EcKey<Private>,
+    curve_id: u8,
+    chunking: u8,
+    md_type: u8,
+) -> TransactionView {
+    let (pub_key_info, _) = compute_ec_pub_key_hash(private_key, curve_id, chunking, md_type);
+    let sub_signature_len = get_curve_field_len(private_key) * 2;
+    let signature_count = get_signature_count(chunking, SUB_SIGNATURE_COUNT);
+    sign_tx_with(tx, pub_key_info, sub_signature_len, signature_count, |sub_message| {
+        ecdsa_sign(private_key, md_type, sub_message)
+    })
+}
 
+fn deploy_passport_lock(context: &mut Context, args: Vec<u8>) -> (Script, Vec<CellDep>) {
     // deploy contract
-    let mut context = Context::default();
     let contract_bin: Bytes = Loader::default().load_binary("ckb-passport-lock");
     let out_point = context.deploy_cell(contract_bin);
 
@@ -165,14 +595,36 @@ fn test_wrong_signature() {
     let rsa_out_point = context.deploy_cell(rsa_bin);
     let rsa_dep = CellDep::new_builder().out_point(rsa_out_point).build();
 
-    let (_, public_key_hash) = compute_pub_key_hash(&public_key, false);
+    let secp256k1_bin: Bytes = fs::read("../ckb-production-scripts/build/secp256k1_blake2b_sighash_all_dual")
+        .expect("load secp256k1")
+        .into();
+    let secp256k1_out_point = context.deploy_cell(secp256k1_bin);
+    let secp256k1_dep = CellDep::new_builder().out_point(secp256k1_out_point).build();
+    let secp256k1_data_bin: Bytes = fs::read("../ckb-production-scripts/build/secp256k1_data")
+        .expect("load secp256k1 data")
+        .into();
+    let secp256k1_data_out_point = context.deploy_cell(secp256k1_data_bin);
+    let secp256k1_data_dep = CellDep::new_builder().out_point(secp256k1_data_out_point).build();
 
     // prepare scripts
     let lock_script = context
-        .build_script(&out_point, public_key_hash.into())
+        .build_script(&out_point, args.into())
         .expect("script");
     let lock_script_dep = CellDep::new_builder().out_point(out_point).build();
 
+    let cell_deps = vec![lock_script_dep, rsa_dep, secp256k1_dep, secp256k1_data_dep];
+    (lock_script, cell_deps)
+}
+
+fn build_test_context(public_key_hash: Vec<u8>) -> (Context, TransactionView) {
+    let mut context = Context::default();
+    let (lock_script, cell_deps) = deploy_passport_lock(&mut context, public_key_hash);
+    let tx = build_test_tx(&mut context, lock_script, cell_deps);
+    (context, tx)
+}
+
+// Spends two cells of the lock into two cells of the same lock
+fn build_test_tx(context: &mut Context, lock_script: Script, cell_deps: Vec<CellDep>) -> TransactionView {
     // prepare cells
     let input_out_point1 = context.create_cell(
         CellOutput::new_builder()
@@ -219,108 +671,2592 @@ fn test_wrong_signature() {
         .inputs(inputs)
         .outputs(outputs)
         .outputs_data(outputs_data.pack())
-        .cell_dep(lock_script_dep)
-        .cell_dep(rsa_dep)
+        .cell_deps(cell_deps)
         .witnesses(witnesses.pack())
         .build();
-    let tx = context.complete_tx(tx);
+    context.complete_tx(tx)
+}
+
+// Asserts that the transaction passes verification or fails with the expected error
+fn assert_result(result: Result<u64, ckb_tool::ckb_error::Error>, expected: Option<TransactionScriptError>) {
+    match expected {
+        None => {
+            result.expect("pass verification");
+        }
+        Some(expected) => {
+            assert_error_eq!(result.unwrap_err(), expected);
+        }
+    }
+}
+
+fn test_success_with_key_bits(bits: u32) {
+    let (private_key, public_key) = generate_random_key(bits);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+    // run
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_success_1024() {
+    test_success_with_key_bits(1024);
+}
+
+#[test]
+fn test_success_2048() {
+    test_success_with_key_bits(2048);
+}
+
+#[test]
+fn test_success_4096() {
+    test_success_with_key_bits(4096);
+}
+
+#[test]
+fn test_success_sha2_digests() {
+    let (private_key, public_key) = generate_random_key(2048);
+    for md_type in &[ISO9796_2_MD_SHA224, ISO9796_2_MD_SHA256, ISO9796_2_MD_SHA384, ISO9796_2_MD_SHA512] {
+        let (_, public_key_hash) = compute_pub_key_hash(&public_key, *md_type, false);
+        let (context, tx) = build_test_context(public_key_hash);
+
+        // sign
+        let tx = sign_tx(tx, &private_key, &public_key, *md_type, false, iso9796_2_sign);
+
+        // run
+        context
+            .verify_tx(&tx, MAX_CYCLES)
+            .expect("pass verification");
+    }
+}
+
+#[test]
+fn test_unsupported_digest() {
+    // MD5
+    let md_type = 3;
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, md_type, false);
+    let (context, tx) = build_test_context(public_key_hash);
 
     // sign
-    let tx = sign_tx(tx, &private_key, &public_key, false);
+    let tx = sign_tx(tx, &private_key, &public_key, md_type, false, iso9796_2_sign);
 
     // run
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     let script_cell_index = 0;
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(ERROR_ISO97962_INVALID_ARG9).input_lock_script(script_cell_index)
+        ScriptError::ValidationFailure(UNSUPPORTED_DIGEST).input_lock_script(script_cell_index)
     );
 }
 
+#[test]
+fn test_min_digest() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let ec_private_key = generate_random_ec_key(Nid::X9_62_PRIME256V1);
+
+    // (minimum md_type of the args, ECDSA signer, md_type of the signer, error)
+    let cases = vec![
+        (ISO9796_2_MD_SHA256, false, ISO9796_2_MD_SHA256, None),
+        (ISO9796_2_MD_SHA256, false, ISO9796_2_MD_SHA512, None),
+        (ISO9796_2_MD_SHA256, false, ISO9796_2_MD_SHA1, Some(DIGEST_TOO_WEAK)),
+        (ISO9796_2_MD_SHA256, false, ISO9796_2_MD_SHA224, Some(DIGEST_TOO_WEAK)),
+        (ISO9796_2_MD_SHA256, true, ISO9796_2_MD_SHA256, None),
+        (ISO9796_2_MD_SHA256, true, ISO9796_2_MD_SHA1, Some(DIGEST_TOO_WEAK)),
+        (ISO9796_2_MD_SHA1, false, ISO9796_2_MD_SHA1, None),
+        (3, false, ISO9796_2_MD_SHA256, Some(INVALID_ARGUMENT)),
+    ];
+    for (min_md_type, ecdsa, md_type, error) in cases {
+        let public_key_hash = if ecdsa {
+            compute_ec_pub_key_hash(&ec_private_key, CURVE_P256, CHUNKING_DEFAULT, md_type).1
+        } else {
+            compute_pub_key_hash(&public_key, md_type, false).1
+        };
+        let mut args = public_key_hash;
+        args.extend_from_slice(&[0x80, 0x80, FLAG_MIN_DIGEST, min_md_type]);
+        let (context, tx) = build_test_context(args);
+
+        // sign
+        let tx = if ecdsa {
+            sign_tx_ecdsa(tx, &ec_private_key, CURVE_P256, CHUNKING_DEFAULT, md_type)
+        } else {
+            sign_tx(tx, &private_key, &public_key, md_type, false, iso9796_2_sign)
+        };
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
 
 #[test]
-fn test_wrong_pub_key() {
-    let (private_key, public_key) = generate_random_key();
+fn test_unsupported_key_size() {
+    for bits in &[1536, 3072] {
+        let (private_key, public_key) = generate_random_key(*bits);
+        let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+        let (context, tx) = build_test_context(public_key_hash);
 
-    // deploy contract
-    let mut context = Context::default();
-    let contract_bin: Bytes = Loader::default().load_binary("ckb-passport-lock");
-    let out_point = context.deploy_cell(contract_bin);
+        // sign
+        let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
 
-    let rsa_bin: Bytes = fs::read("../ckb-production-scripts/build/validate_signature_rsa")
-        .expect("load rsa")
-        .into();
-    let rsa_out_point = context.deploy_cell(rsa_bin);
-    let rsa_dep = CellDep::new_builder().out_point(rsa_out_point).build();
+        // run
+        let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+        let script_cell_index = 0;
+        assert_error_eq!(
+            err,
+            ScriptError::ValidationFailure(UNSUPPORTED_KEY_SIZE).input_lock_script(script_cell_index)
+        );
+    }
+}
 
-    let (_, public_key_hash) = compute_pub_key_hash(&public_key, false);
+#[test]
+fn test_wrong_signature() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx) = build_test_context(public_key_hash);
 
-    // prepare scripts
-    let lock_script = context
-        .build_script(&out_point, public_key_hash.into())
-        .expect("script");
-    let lock_script_dep = CellDep::new_builder().out_point(out_point).build();
+    // sign
+    let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, pkcs1_sign);
 
-    // prepare cells
-    let input_out_point1 = context.create_cell(
-        CellOutput::new_builder()
-            .capacity(1000u64.pack())
-            .lock(lock_script.clone())
-            .build(),
-        Bytes::new(),
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ERROR_ISO97962_INVALID_ARG9).input_lock_script(script_cell_index)
     );
-    let input_out_point2 = context.create_cell(
-        CellOutput::new_builder()
-            .capacity(300u64.pack())
-            .lock(lock_script.clone())
-            .build(),
-        Bytes::new(),
+}
+
+
+#[test]
+fn test_wrong_pub_key() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, true, iso9796_2_sign);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(WRONG_PUB_KEY).input_lock_script(script_cell_index)
     );
+}
 
-    let inputs = vec![
-        CellInput::new_builder()
-            .previous_output(input_out_point1)
-            .build(),
-        CellInput::new_builder()
-            .previous_output(input_out_point2)
-            .build(),
-    ];
-    let outputs = vec![
-        CellOutput::new_builder()
-            .capacity(500u64.pack())
-            .lock(lock_script.clone())
-            .build(),
-        CellOutput::new_builder()
-            .capacity(800u64.pack())
-            .lock(lock_script)
-            .build(),
+#[test]
+fn test_ecdsa_success() {
+    let curves = [
+        (Nid::X9_62_PRIME256V1, CURVE_P256),
+        (Nid::SECP384R1, CURVE_P384),
+        (Nid::from_raw(NID_BRAINPOOL_P256R1), CURVE_BRAINPOOL_P256R1),
+        (Nid::from_raw(NID_BRAINPOOL_P384R1), CURVE_BRAINPOOL_P384R1),
     ];
+    for (curve, curve_id) in &curves {
+        for md_type in &[ISO9796_2_MD_SHA1, ISO9796_2_MD_SHA256, ISO9796_2_MD_SHA512] {
+            let private_key = generate_random_ec_key(*curve);
+            let (_, public_key_hash) = compute_ec_pub_key_hash(&private_key, *curve_id, CHUNKING_DEFAULT, *md_type);
+            let (context, tx) = build_test_context(public_key_hash);
 
-    let outputs_data = vec![Bytes::new(); 2];
-    let mut witnesses = vec![];
-    for _ in 0..inputs.len() {
-        witnesses.push(Bytes::new())
+            // sign
+            let tx = sign_tx_ecdsa(tx, &private_key, *curve_id, CHUNKING_DEFAULT, *md_type);
+
+            // run
+            context
+                .verify_tx(&tx, MAX_CYCLES)
+                .expect("pass verification");
+        }
     }
+}
 
-    // build transaction
-    let tx = TransactionBuilder::default()
-        .inputs(inputs)
-        .outputs(outputs)
-        .outputs_data(outputs_data.pack())
-        .cell_dep(lock_script_dep)
-        .cell_dep(rsa_dep)
-        .witnesses(witnesses.pack())
-        .build();
-    let tx = context.complete_tx(tx);
+#[test]
+fn test_ecdsa_wrong_signature() {
+    let private_key = generate_random_ec_key(Nid::X9_62_PRIME256V1);
+    let other_private_key = generate_random_ec_key(Nid::X9_62_PRIME256V1);
+    let (pub_key_info, public_key_hash) = compute_ec_pub_key_hash(&private_key, CURVE_P256, CHUNKING_DEFAULT, ISO9796_2_MD_SHA256);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign with another key but keep the committed public key
+    let tx = sign_tx_with(tx, pub_key_info, 64, SUB_SIGNATURE_COUNT, |sub_message| {
+        ecdsa_sign(&other_private_key, ISO9796_2_MD_SHA256, sub_message)
+    });
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(ECDSA_VERIFY_ERROR).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_ecdsa_unsupported_curve() {
+    // the layout of a 256-bit curve with an unknown curve id
+    let private_key = generate_random_ec_key(Nid::X9_62_PRIME256V1);
+    let (_, public_key_hash) = compute_ec_pub_key_hash(&private_key, 5, CHUNKING_DEFAULT, ISO9796_2_MD_SHA256);
+    let (context, tx) = build_test_context(public_key_hash);
 
     // sign
-    let tx = sign_tx(tx, &private_key, &public_key, true);
+    let tx = sign_tx_ecdsa(tx, &private_key, 5, CHUNKING_DEFAULT, ISO9796_2_MD_SHA256);
 
     // run
     let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
     let script_cell_index = 0;
     assert_error_eq!(
         err,
-        ScriptError::ValidationFailure(WRONG_PUB_KEY).input_lock_script(script_cell_index)
+        ScriptError::ValidationFailure(UNSUPPORTED_CURVE).input_lock_script(script_cell_index)
     );
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_pkcs1_success() {
+    let (private_key, public_key) = generate_random_key(2048);
+    for padding in &[PKCS1_V15_PADDING, PKCS1_PSS_PADDING] {
+        for md_type in &[ISO9796_2_MD_SHA1, ISO9796_2_MD_SHA256] {
+            let header = [RSA_ALGORITHM_ID, get_key_size(&public_key), *padding, *md_type];
+            let (_, public_key_hash) = compute_rsa_pub_key_hash(&public_key, header, false);
+            let (context, tx) = build_test_context(public_key_hash);
+
+            // sign
+            let tx = sign_tx_pkcs1(tx, &private_key, &public_key, *padding, *md_type);
+
+            // run
+            context
+                .verify_tx(&tx, MAX_CYCLES)
+                .expect("pass verification");
+        }
+    }
+}
+
+#[test]
+fn test_pkcs1_wrong_padding() {
+    // a PSS signature under a lock committed to PKCS#1 v1.5
+    let (private_key, public_key) = generate_random_key(1024);
+    let header = [RSA_ALGORITHM_ID, get_key_size(&public_key), PKCS1_V15_PADDING, ISO9796_2_MD_SHA256];
+    let (rsa_info, public_key_hash) = compute_rsa_pub_key_hash(&public_key, header, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx_with(tx, rsa_info, get_key_len(&public_key), 1, |message| {
+        pss_sign(&private_key, ISO9796_2_MD_SHA256, message)
+    });
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(RSA_VERIFY_ERROR).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_pkcs1_unsupported_padding() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let header = [RSA_ALGORITHM_ID, get_key_size(&public_key), 2, ISO9796_2_MD_SHA256];
+    let (rsa_info, public_key_hash) = compute_rsa_pub_key_hash(&public_key, header, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx_with(tx, rsa_info, get_key_len(&public_key), 1, |message| {
+        pkcs1_sign(&private_key, ISO9796_2_MD_SHA256, message)
+    });
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(UNSUPPORTED_PADDING).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_iso9796_2_chunking() {
+    let (private_key, public_key) = generate_random_key(1024);
+    for chunking in &[CHUNKING_16, CHUNKING_32] {
+        let header = [ISO9796_2_ALGORITHM_ID, get_key_size(&public_key), ISO9796_2_PADDING | *chunking, ISO9796_2_MD_SHA256];
+        let (rsa_info, public_key_hash) = compute_rsa_pub_key_hash(&public_key, header, false);
+        let (context, tx) = build_test_context(public_key_hash);
+
+        // sign
+        let signature_count = get_signature_count(*chunking, SUB_SIGNATURE_COUNT);
+        let tx = sign_tx_with(tx, rsa_info, get_key_len(&public_key), signature_count, |sub_message| {
+            iso9796_2_sign(&private_key, ISO9796_2_MD_SHA256, sub_message)
+        });
+
+        // run
+        context
+            .verify_tx(&tx, MAX_CYCLES)
+            .expect("pass verification");
+    }
+}
+
+#[test]
+fn test_ecdsa_single_signature() {
+    let private_key = generate_random_ec_key(Nid::X9_62_PRIME256V1);
+    let (_, public_key_hash) = compute_ec_pub_key_hash(&private_key, CURVE_P256, CHUNKING_32, ISO9796_2_MD_SHA256);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx_ecdsa(tx, &private_key, CURVE_P256, CHUNKING_32, ISO9796_2_MD_SHA256);
+
+    // run
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_chunking_mismatch() {
+    // a lock committed to 16-byte chunks signed with the default four 8-byte chunks
+    let (private_key, public_key) = generate_random_key(1024);
+    let header = [ISO9796_2_ALGORITHM_ID, get_key_size(&public_key), ISO9796_2_PADDING | CHUNKING_16, ISO9796_2_MD_SHA1];
+    let (rsa_info, public_key_hash) = compute_rsa_pub_key_hash(&public_key, header, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx_with(tx, rsa_info, get_key_len(&public_key), SUB_SIGNATURE_COUNT, |sub_message| {
+        iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, sub_message)
+    });
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(RSA_PUB_KEY_SIG_LENGTH_ERROR).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_unsupported_chunk_size() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let header = [ISO9796_2_ALGORITHM_ID, get_key_size(&public_key), ISO9796_2_PADDING | (4 << 4), ISO9796_2_MD_SHA1];
+    let (rsa_info, public_key_hash) = compute_rsa_pub_key_hash(&public_key, header, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx_with(tx, rsa_info, get_key_len(&public_key), SUB_SIGNATURE_COUNT, |sub_message| {
+        iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, sub_message)
+    });
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(UNSUPPORTED_CHUNK_SIZE).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_versioned_witness() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx_versioned(tx, 1, rsa_info, get_key_len(&public_key), SUB_SIGNATURE_COUNT, |sub_message| {
+        iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, sub_message)
+    });
+
+    // run
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_versioned_witness_ecdsa() {
+    let private_key = generate_random_ec_key(Nid::X9_62_PRIME256V1);
+    let (pub_key_info, public_key_hash) = compute_ec_pub_key_hash(&private_key, CURVE_P256, CHUNKING_DEFAULT, ISO9796_2_MD_SHA256);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let sub_signature_len = get_curve_field_len(&private_key) * 2;
+    let tx = sign_tx_versioned(tx, 1, pub_key_info, sub_signature_len, SUB_SIGNATURE_COUNT, |sub_message| {
+        ecdsa_sign(&private_key, ISO9796_2_MD_SHA256, sub_message)
+    });
+
+    // run
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_unsupported_witness_version() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    let tx = sign_tx_versioned(tx, 2, rsa_info, get_key_len(&public_key), SUB_SIGNATURE_COUNT, |sub_message| {
+        iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, sub_message)
+    });
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(UNSUPPORTED_WITNESS_VERSION).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_witness_too_short() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (mut rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // a versioned lock whose N misses its last byte, and a legacy lock shorter than any layout
+    rsa_info.pop();
+    let versioned_tx = sign_tx_versioned(tx.clone(), 1, rsa_info, get_key_len(&public_key), SUB_SIGNATURE_COUNT, |sub_message| {
+        iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, sub_message)
+    });
+    let legacy_tx = sign_tx_with(tx, vec![0u8; 4], 16, 1, |_| vec![0u8; 16]);
+
+    // run
+    for tx in &[versioned_tx, legacy_tx] {
+        let err = context.verify_tx(tx, MAX_CYCLES).unwrap_err();
+        let script_cell_index = 0;
+        assert_error_eq!(
+            err,
+            ScriptError::ValidationFailure(WITNESS_TOO_SHORT).input_lock_script(script_cell_index)
+        );
+    }
+}
+
+#[test]
+fn test_witness_trailing_bytes() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (mut rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // sign
+    rsa_info.push(0);
+    let tx = sign_tx_versioned(tx, 1, rsa_info, get_key_len(&public_key), SUB_SIGNATURE_COUNT, |sub_message| {
+        iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, sub_message)
+    });
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(WITNESS_TRAILING_BYTES).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_extra_witnesses() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // the second input of the group and a witness beyond the inputs are longer than the
+    // contract's witness buffer
+    let mut group_witness = vec![0u8; 40 * 1024];
+    rand_bytes(&mut group_witness).unwrap();
+    let mut extra_witness = vec![0u8; 100 * 1024 + 7];
+    rand_bytes(&mut extra_witness).unwrap();
+    let tx = tx
+        .as_advanced_builder()
+        .set_witnesses(vec![
+            Bytes::new().pack(),
+            Bytes::from(group_witness).pack(),
+            Bytes::from(extra_witness).pack(),
+        ])
+        .build();
+
+    // sign
+    let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+    // run
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_extended_args() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+
+    // a flag without payload in the second flags byte
+    for flags in &[vec![0x80u8, FLAG_DELEGATION]] {
+        let mut args = public_key_hash.clone();
+        args.extend_from_slice(flags);
+        let (context, tx) = build_test_context(args);
+
+        // sign
+        let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+        // run
+        context
+            .verify_tx(&tx, MAX_CYCLES)
+            .expect("pass verification");
+    }
+}
+
+#[test]
+fn test_invalid_extended_args() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+
+    let cases = vec![
+        (vec![0x80u8, 0x80, 0x80, 0x40], UNSUPPORTED_ARGS_FLAGS),
+        (vec![0x80u8], INVALID_ARGUMENT),
+        (vec![0u8, 0], INVALID_ARGUMENT),
+        // flags bytes which encode no flag or end with a zero byte are not canonical
+        (vec![0u8], INVALID_ARGUMENT),
+        (vec![0x80u8, 0], INVALID_ARGUMENT),
+        (vec![0x80u8 | FLAG_DELEGATION, 0], INVALID_ARGUMENT),
+        // a document reveal without passive authentication
+        ([vec![0x80u8, 0x80, FLAG_DOCUMENT | FLAG_DOCUMENT_REVEAL], vec![0u8; 32]].concat(), INVALID_ARGUMENT),
+        // a backup key, whose signature carries no whitelist proofs, with a whitelist
+        ([vec![0x80u8 | FLAG_BACKUP_KEY, FLAG_WHITELIST], vec![0u8; 52]].concat(), INVALID_ARGUMENT),
+        // guardians, which recover past any spending limit, with a spending limit
+        ([vec![0x80u8 | FLAG_GUARDIANS, FLAG_TX_SPENDING_LIMIT], vec![0u8; 44]].concat(), INVALID_ARGUMENT),
+        // a vault, whose unvaulting neither a whitelist nor a spending limit lets through, with either
+        ([vec![0x80u8, FLAG_WHITELIST | FLAG_VAULT], vec![0u8; 60]].concat(), INVALID_ARGUMENT),
+        ([vec![0x80u8, FLAG_TX_SPENDING_LIMIT | FLAG_VAULT], vec![0u8; 44]].concat(), INVALID_ARGUMENT),
+        ([vec![0x80u8, 0x80 | FLAG_VAULT, FLAG_EPOCH_SPENDING_LIMIT], vec![0u8; 76]].concat(), INVALID_ARGUMENT),
+        // a rotation grace, whose claimed lock the whitelist cannot commit to, with a whitelist
+        ([vec![0x80u8 | FLAG_ROTATION_GRACE, FLAG_WHITELIST], vec![0u8; 68]].concat(), INVALID_ARGUMENT),
+        // a pending recovery without guardians
+        ([vec![0x80u8, 0x80, FLAG_RECOVERY_PENDING], vec![0u8; 32]].concat(), INVALID_ARGUMENT),
+        // a since constraint, whose since flags conflict with those of the other delays
+        ([vec![FLAG_SINCE | FLAG_INHERITANCE], vec![0u8; 48]].concat(), INVALID_ARGUMENT),
+        ([vec![FLAG_SINCE | FLAG_ROTATION_GRACE], vec![0u8; 44]].concat(), INVALID_ARGUMENT),
+        ([vec![0x80u8 | FLAG_SINCE, FLAG_TX_SPENDING_LIMIT], vec![0u8; 24]].concat(), INVALID_ARGUMENT),
+        ([vec![0x80u8 | FLAG_GUARDIANS | FLAG_SINCE, 0x80, FLAG_RECOVERY_PENDING], vec![0u8; 68]].concat(), INVALID_ARGUMENT),
+    ];
+    for (flags, error) in cases {
+        let mut args = public_key_hash.clone();
+        args.extend_from_slice(&flags);
+        let (context, tx) = build_test_context(args);
+
+        // sign
+        let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+        // run
+        let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+        let script_cell_index = 0;
+        assert_error_eq!(
+            err,
+            ScriptError::ValidationFailure(error).input_lock_script(script_cell_index)
+        );
+    }
+}
+
+fn build_multisig_test_context(threshold: u8, keys: &[(PKey<Private>, PKey<Public>)]) -> (Context, TransactionView, Vec<Vec<u8>>) {
+    let pub_key_hashes: Vec<Vec<u8>> = keys
+        .iter()
+        .map(|(_, public_key)| compute_pub_key_hash(public_key, ISO9796_2_MD_SHA1, false).1)
+        .collect();
+    let mut args = compute_multisig_hash(threshold, &pub_key_hashes);
+    args.push(FLAG_MULTISIG);
+    let (context, tx) = build_test_context(args);
+    (context, tx, pub_key_hashes)
+}
+
+#[test]
+fn test_multisig_success() {
+    let keys: Vec<_> = (0..3).map(|_| generate_random_key(1024)).collect();
+    let (context, tx, pub_key_hashes) = build_multisig_test_context(2, &keys);
+
+    // sign with the first and the last passport
+    let signers = [keys[0].clone(), keys[2].clone()];
+    let tx = sign_tx_multisig(tx, 2, &pub_key_hashes, &signers);
+
+    // run
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_multisig_threshold_not_met() {
+    let keys: Vec<_> = (0..3).map(|_| generate_random_key(1024)).collect();
+    let (context, tx, pub_key_hashes) = build_multisig_test_context(2, &keys);
+
+    // sign
+    let tx = sign_tx_multisig(tx, 2, &pub_key_hashes, &keys[1..2]);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(MULTISIG_THRESHOLD_NOT_MET).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_multisig_duplicate_signer() {
+    let keys: Vec<_> = (0..3).map(|_| generate_random_key(1024)).collect();
+    let (context, tx, pub_key_hashes) = build_multisig_test_context(2, &keys);
+
+    // sign
+    let signers = [keys[0].clone(), keys[0].clone()];
+    let tx = sign_tx_multisig(tx, 2, &pub_key_hashes, &signers);
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(DUPLICATE_SIGNER).input_lock_script(script_cell_index)
+    );
+}
+
+fn build_backup_key_test_context(public_key: &PKey<Public>, backup_key: &Privkey) -> (Context, TransactionView) {
+    let (_, mut args) = compute_pub_key_hash(public_key, ISO9796_2_MD_SHA1, false);
+    args.push(FLAG_BACKUP_KEY);
+    args.append(&mut compute_secp256k1_pub_key_hash(backup_key));
+    build_test_context(args)
+}
+
+#[test]
+fn test_backup_key() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let backup_key = Generator::random_privkey();
+
+    // unlock with the backup key and with the passport
+    let (context, tx) = build_backup_key_test_context(&public_key, &backup_key);
+    let backup_tx = sign_tx_secp256k1(tx.clone(), &backup_key);
+    let passport_tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+    // run
+    for tx in &[backup_tx, passport_tx] {
+        context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect("pass verification");
+    }
+}
+
+#[test]
+fn test_wrong_backup_key() {
+    let (_, public_key) = generate_random_key(1024);
+    let backup_key = Generator::random_privkey();
+    let (context, tx) = build_backup_key_test_context(&public_key, &backup_key);
+
+    // sign
+    let tx = sign_tx_secp256k1(tx, &Generator::random_privkey());
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(WRONG_PUB_KEY).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_backup_key_tx_spending_limit() {
+    let (_, public_key) = generate_random_key(1024);
+    let backup_key = Generator::random_privkey();
+    let (_, mut args) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    args.extend_from_slice(&[0x80 | FLAG_BACKUP_KEY, FLAG_TX_SPENDING_LIMIT]);
+    args.append(&mut compute_secp256k1_pub_key_hash(&backup_key));
+    args.extend_from_slice(&TX_SPENDING_LIMIT.to_le_bytes());
+    args.extend_from_slice(&TX_SPENDING_LIMIT_DELAY.to_le_bytes());
+
+    // (outputs of the passport lock, outputs of another lock, error)
+    let cases = vec![
+        (vec![800], vec![500], None),
+        (vec![300], vec![1000], Some(SPENDING_LIMIT_EXCEEDED)),
+    ];
+    for (passport_outputs, other_outputs, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let other_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let (lock_script, cell_deps) = deploy_passport_lock(&mut context, args.clone());
+        let outputs = passport_outputs
+            .into_iter()
+            .map(|capacity| (lock_script.clone(), capacity, Bytes::new()))
+            .chain(other_outputs.into_iter().map(|capacity| (other_lock.clone(), capacity, Bytes::new())))
+            .collect();
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, Bytes::new(), 0, outputs);
+        let tx = sign_tx_secp256k1(tx, &backup_key);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+struct RecoveryTestContext {
+    context: Context,
+    lock_script: Script,
+    pending_lock_script: Script,
+    cell_deps: Vec<CellDep>,
+    guardians: Vec<Script>,
+    recovery_witness: passport_types::RecoveryWitness,
+}
+
+// Guardians are always-success locks, the passport lock commits to 2 of 3 of them
+fn build_recovery_test_context() -> RecoveryTestContext {
+    let (_, public_key) = generate_random_key(1024);
+    let (_, mut args) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let mut context = Context::default();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let guardians: Vec<Script> = (1..=3u8)
+        .map(|i| {
+            context
+                .build_script(&always_success_out_point, Bytes::from(vec![i]))
+                .expect("script")
+        })
+        .collect();
+    let mut guardian_lock_hashes: Vec<[u8; 32]> = guardians
+        .iter()
+        .map(|guardian| guardian.calc_script_hash().unpack())
+        .collect();
+    guardian_lock_hashes.sort();
+
+    let mut config = vec![2u8, 3];
+    config.append(&mut guardian_lock_hashes.concat());
+    let guardians_payload = [blake160(&config).to_vec(), RECOVERY_DELAY.to_le_bytes().to_vec()].concat();
+
+    // the new lock is the lock of the first guardian
+    let new_lock_hash: [u8; 32] = guardians[0].calc_script_hash().unpack();
+    let recovery_witness = passport_types::RecoveryWitness::new_builder()
+        .version(1.into())
+        .threshold(2.into())
+        .guardian_lock_hashes(
+            passport_types::Byte32Vec::new_builder()
+                .set(guardian_lock_hashes.iter().map(|hash| (*hash).into()).collect())
+                .build(),
+        )
+        .new_lock_hash(new_lock_hash.into())
+        .build();
+
+    // the pending cells add the recovery pending flag and the new lock hash to the args
+    let mut pending_args = args.clone();
+    pending_args.extend_from_slice(&[0x80 | FLAG_GUARDIANS, 0x80, FLAG_RECOVERY_PENDING]);
+    pending_args.extend_from_slice(&guardians_payload);
+    pending_args.extend_from_slice(&new_lock_hash);
+    args.push(FLAG_GUARDIANS);
+    args.extend_from_slice(&guardians_payload);
+
+    let (lock_script, mut cell_deps) = deploy_passport_lock(&mut context, args);
+    let pending_lock_script = lock_script.clone().as_builder().args(Bytes::from(pending_args).pack()).build();
+    cell_deps.push(CellDep::new_builder().out_point(always_success_out_point).build());
+    RecoveryTestContext { context, lock_script, pending_lock_script, cell_deps, guardians, recovery_witness }
+}
+
+// Spends cells of the input lock with the given data and since, together with the given guardians
+fn build_recovery_tx(
+    test_context: &mut RecoveryTestContext,
+    input_lock: &Script,
+    guardians: &[Script],
+    input_data: Bytes,
+    since: u64,
+    outputs: Vec<(Script, u64, Bytes)>,
+) -> TransactionView {
+    let RecoveryTestContext { context, cell_deps, recovery_witness, .. } = test_context;
+    let mut inputs = vec![];
+    for capacity in &[1000u64, 300] {
+        let out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(capacity.pack())
+                .lock(input_lock.clone())
+                .build(),
+            input_data.clone(),
+        );
+        inputs.push(CellInput::new_builder().previous_output(out_point).since(since.pack()).build());
+    }
+    for guardian in guardians {
+        let out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(100u64.pack())
+                .lock(guardian.clone())
+                .build(),
+            Bytes::new(),
+        );
+        inputs.push(CellInput::new_builder().previous_output(out_point).build());
+    }
+    let outputs_data: Vec<Bytes> = outputs.iter().map(|(_, _, data)| data.clone()).collect();
+    let outputs: Vec<CellOutput> = outputs
+        .into_iter()
+        .map(|(lock, capacity, _)| CellOutput::new_builder().capacity(capacity.pack()).lock(lock).build())
+        .collect();
+
+    let witness = WitnessArgs::new_builder()
+        .lock(Some(Bytes::from(recovery_witness.as_slice().to_vec())).pack())
+        .build();
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_deps(cell_deps.clone())
+        .witness(witness.as_bytes().pack())
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_guardian_recovery() {
+    let mut test_context = build_recovery_test_context();
+    let lock_script = test_context.lock_script.clone();
+    let pending_lock_script = test_context.pending_lock_script.clone();
+    let guardians = test_context.guardians.clone();
+
+    // two guardians move the passport cells to pending cells
+    let request_tx = build_recovery_tx(
+        &mut test_context,
+        &lock_script,
+        &guardians[1..],
+        Bytes::new(),
+        0,
+        vec![(pending_lock_script.clone(), 1300, Bytes::new())],
+    );
+
+    // the pending cells move to the new lock after the delay
+    let finalize_tx = build_recovery_tx(
+        &mut test_context,
+        &pending_lock_script,
+        &[],
+        Bytes::new(),
+        RECOVERY_DELAY,
+        vec![(guardians[0].clone(), 1000, Bytes::new()), (guardians[0].clone(), 300, Bytes::new())],
+    );
+
+    // run
+    for tx in &[request_tx, finalize_tx] {
+        test_context
+            .context
+            .verify_tx(tx, MAX_CYCLES)
+            .expect("pass verification");
+    }
+}
+
+#[test]
+fn test_guardian_recovery_errors() {
+    let mut test_context = build_recovery_test_context();
+    let lock_script = test_context.lock_script.clone();
+    let pending_lock_script = test_context.pending_lock_script.clone();
+    let guardians = test_context.guardians.clone();
+    let new_lock_hash: [u8; 32] = guardians[0].calc_script_hash().unpack();
+    let new_lock_data = Bytes::from(new_lock_hash.to_vec());
+
+    // a single guardian requests the recovery
+    let request_tx = build_recovery_tx(
+        &mut test_context,
+        &lock_script,
+        &guardians[2..],
+        Bytes::new(),
+        0,
+        vec![(pending_lock_script.clone(), 1300, Bytes::new())],
+    );
+
+    // the guardians keep the cells in the passport lock, marked by their data only
+    let unflagged_request_tx = build_recovery_tx(
+        &mut test_context,
+        &lock_script,
+        &guardians[1..],
+        Bytes::new(),
+        0,
+        vec![(lock_script.clone(), 1300, new_lock_data.clone())],
+    );
+
+    // passport cells whose data is the new lock hash are not pending
+    let forged_finalize_tx = build_recovery_tx(
+        &mut test_context,
+        &lock_script,
+        &[],
+        new_lock_data,
+        RECOVERY_DELAY,
+        vec![(guardians[0].clone(), 1300, Bytes::new())],
+    );
+
+    // the pending cells are finalized before the delay
+    let early_finalize_tx = build_recovery_tx(
+        &mut test_context,
+        &pending_lock_script,
+        &[],
+        Bytes::new(),
+        RECOVERY_DELAY - 1,
+        vec![(guardians[0].clone(), 1000, Bytes::new()), (guardians[0].clone(), 300, Bytes::new())],
+    );
+
+    // both pending cells are paid by a single output, which another group could count as well
+    let shared_finalize_tx = build_recovery_tx(
+        &mut test_context,
+        &pending_lock_script,
+        &[],
+        Bytes::new(),
+        RECOVERY_DELAY,
+        vec![(guardians[0].clone(), 1300, Bytes::new())],
+    );
+
+    // run
+    let cases = vec![
+        (request_tx, GUARDIANS_NOT_PRESENT),
+        (unflagged_request_tx, RECOVERY_OUTPUT_MISMATCH),
+        (forged_finalize_tx, GUARDIANS_NOT_PRESENT),
+        (early_finalize_tx, SINCE_NOT_SATISFIED),
+        (shared_finalize_tx, RECOVERY_OUTPUT_MISMATCH),
+    ];
+    for (tx, error) in cases {
+        let result = test_context.context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, Some(ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+fn set_inputs_since(tx: TransactionView, since: u64) -> TransactionView {
+    let inputs: Vec<CellInput> = tx
+        .inputs()
+        .into_iter()
+        .map(|input| input.as_builder().since(since.pack()).build())
+        .collect();
+    tx.as_advanced_builder().set_inputs(inputs).build()
+}
+
+fn epoch_since(number: u64, index: u64, length: u64) -> u64 {
+    0x2000_0000_0000_0000 | (length << 40) | (index << 24) | number
+}
+
+#[test]
+fn test_since_constraint() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+
+    // (required since, input since, error)
+    let cases = vec![
+        (100, 100, None),
+        (100, 99, Some(SINCE_NOT_SATISFIED)),
+        (100, 0x8000_0000_0000_0064, Some(SINCE_MISMATCH)),
+        (epoch_since(5, 1, 2), epoch_since(5, 2, 4), None),
+        (epoch_since(5, 1, 2), epoch_since(5, 1, 4), Some(SINCE_NOT_SATISFIED)),
+        (0xC000_0000_0000_0E10, 0xC000_0000_0000_0E10, None),
+    ];
+    for (required, since, error) in cases {
+        let mut args = public_key_hash.clone();
+        args.push(FLAG_SINCE);
+        args.extend_from_slice(&required.to_le_bytes());
+        let (context, tx) = build_test_context(args);
+
+        // sign
+        let tx = set_inputs_since(tx, since);
+        let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+// The passport lock names an always-success beneficiary, which is returned with the transaction
+fn build_inheritance_test_context(public_key_hash: Vec<u8>) -> (Context, TransactionView, Script) {
+    let mut context = Context::default();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let beneficiary = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("script");
+    let beneficiary_lock_hash: [u8; 32] = beneficiary.calc_script_hash().unpack();
+
+    let mut args = public_key_hash;
+    args.push(FLAG_INHERITANCE);
+    args.extend_from_slice(&beneficiary_lock_hash);
+    args.extend_from_slice(&INHERITANCE_PERIOD.to_le_bytes());
+    let (lock_script, mut cell_deps) = deploy_passport_lock(&mut context, args);
+    cell_deps.push(CellDep::new_builder().out_point(always_success_out_point).build());
+    let tx = build_test_tx(&mut context, lock_script, cell_deps);
+    (context, tx, beneficiary)
+}
+
+// Leaves the witness lock of the passport empty, and spends a cell of the beneficiary if any
+fn claim_inheritance(context: &mut Context, tx: TransactionView, beneficiary: Option<&Script>, since: u64) -> TransactionView {
+    let tx = set_inputs_since(tx, since);
+    let mut witnesses: Vec<packed::Bytes> = tx.witnesses().into_iter().collect();
+    witnesses[0] = WitnessArgs::default().as_bytes().pack();
+    let mut builder = tx.as_advanced_builder();
+    if let Some(beneficiary) = beneficiary {
+        let out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(100u64.pack())
+                .lock(beneficiary.clone())
+                .build(),
+            Bytes::new(),
+        );
+        builder = builder.input(CellInput::new_builder().previous_output(out_point).build());
+        witnesses.push(Bytes::new().pack());
+    }
+    builder.set_witnesses(witnesses).build()
+}
+
+#[test]
+fn test_inheritance_owner() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (context, tx, _) = build_inheritance_test_context(public_key_hash);
+
+    // the passport spends back to itself at any time, which restarts the period
+    let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+    // run
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_inheritance_beneficiary() {
+    let (_, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+
+    // (beneficiary present, input since, error)
+    let cases = vec![
+        (true, INHERITANCE_PERIOD, None),
+        (true, INHERITANCE_PERIOD + 1, None),
+        (true, INHERITANCE_PERIOD - 1, Some(SINCE_NOT_SATISFIED)),
+        (false, INHERITANCE_PERIOD, Some(BENEFICIARY_NOT_PRESENT)),
+    ];
+    for (present, since, error) in cases {
+        let (mut context, tx, beneficiary) = build_inheritance_test_context(public_key_hash.clone());
+        let beneficiary = if present { Some(&beneficiary) } else { None };
+        let tx = claim_inheritance(&mut context, tx, beneficiary, since);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_rotation() {
+    let (old_private_key, old_public_key) = generate_random_key(1024);
+    let (_, old_public_key_hash) = compute_pub_key_hash(&old_public_key, ISO9796_2_MD_SHA1, false);
+    let (_, new_public_key) = generate_random_key(2048);
+    let (_, new_public_key_hash) = compute_pub_key_hash(&new_public_key, ISO9796_2_MD_SHA1, false);
+
+    // the old passport designated the new one as its successor
+    let mut args = old_public_key_hash.clone();
+    args.push(FLAG_ROTATION_GRACE);
+    args.extend_from_slice(&PASSPORT_EXPIRY.to_le_bytes());
+    args.extend_from_slice(&GRACE_CLAIM_DELAY.to_le_bytes());
+    let undesignated_args = [args.clone(), vec![0u8; 20]].concat();
+    args.extend_from_slice(&new_public_key_hash);
+    let mut context = Context::default();
+    let (lock_script, cell_deps) = deploy_passport_lock(&mut context, args.clone());
+    let other_lock = lock_script
+        .clone()
+        .as_builder()
+        .args(Bytes::from(new_public_key_hash.clone()).pack())
+        .build();
+
+    // the indexer finds two cells of the old passport and one of another lock
+    let cells: Vec<rotation::LiveCell> = [(1000u64, &lock_script), (300, &lock_script), (500, &other_lock)]
+        .iter()
+        .map(|(capacity, lock)| {
+            let output = CellOutput::new_builder()
+                .capacity(capacity.pack())
+                .lock((*lock).clone())
+                .build();
+            let data = Bytes::from(vec![1u8]);
+            let out_point = context.create_cell(output.clone(), data.clone());
+            rotation::LiveCell { out_point, output, data }
+        })
+        .collect();
+
+    let mut new_pub_key_hash = [0u8; 20];
+    new_pub_key_hash.copy_from_slice(&new_public_key_hash);
+    let mut old_pub_key_hash = [0u8; 20];
+    old_pub_key_hash.copy_from_slice(&old_public_key_hash);
+    let new_expiry = PASSPORT_EXPIRY + 10 * 365 * 24 * 3600;
+    let rotation = rotation::Rotation {
+        lock_code_hash: lock_script.code_hash(),
+        old_pub_key_hash,
+        new_pub_key_hash,
+        new_expiry,
+    };
+    let designated_args = rotation
+        .designate_args(&LockArgs::parse(&undesignated_args).expect("args"))
+        .expect("designated args");
+    assert_eq!(designated_args.to_vec(), args);
+    assert_eq!(rotation.find_cells(&cells).len(), 2);
+    let tx = rotation.build_tx(&cells, cell_deps, 100).expect("rotation tx");
+
+    // every cell is re-created for the new passport, the largest one pays the fee, and the
+    // grace starts over without successor
+    let mut new_args = LockArgs::new(new_pub_key_hash);
+    let mut grace = new_expiry.to_le_bytes().to_vec();
+    grace.extend_from_slice(&GRACE_CLAIM_DELAY.to_le_bytes());
+    grace.extend_from_slice(&[0u8; 20]);
+    new_args.set_payload(ckb_passport_lock_sdk::args::FLAG_ROTATION_GRACE, &grace);
+    let capacities: Vec<u64> = tx.outputs().into_iter().map(|output| output.capacity().unpack()).collect();
+    assert_eq!(capacities, vec![900, 300]);
+    for output in tx.outputs().into_iter() {
+        assert_eq!(output.lock().args().raw_data().to_vec(), new_args.to_vec());
+    }
+
+    // the old passport signs the rotation
+    let tx = context.complete_tx(tx);
+    let tx = sign_tx(tx, &old_private_key, &old_public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+
+    // the rotated args are still known to the contract
+    assert_eq!(LockArgs::parse(&new_args.to_vec()), Some(new_args));
+    assert_eq!(rotation.build_tx(&cells[2..], vec![], 100).unwrap_err(), rotation::Error::NoCells);
+
+    // the rotation cancels a pending guardian recovery, and leaves the cells of a vault
+    let mut pending_args = LockArgs::new(old_pub_key_hash);
+    pending_args.set_payload(ckb_passport_lock_sdk::args::FLAG_GUARDIANS, &[0u8; 28]);
+    pending_args.set_payload(ckb_passport_lock_sdk::args::FLAG_RECOVERY_PENDING, &[1u8; 32]);
+    let mut recovered_args = LockArgs::new(new_pub_key_hash);
+    recovered_args.set_payload(ckb_passport_lock_sdk::args::FLAG_GUARDIANS, &[0u8; 28]);
+    assert_eq!(rotation.rotate_args(&pending_args), Ok(recovered_args));
+    let mut vault_args = LockArgs::new(old_pub_key_hash);
+    vault_args.set_payload(ckb_passport_lock_sdk::args::FLAG_VAULT, &[0u8; 28]);
+    assert_eq!(rotation.rotate_args(&vault_args), Err(rotation::Error::VaultedCell));
+}
+
+// Spends two cells of the lock (1000 and 300) with the data and since of the inputs into
+// the outputs, given as (lock, capacity, data)
+fn build_spend_tx(
+    context: &mut Context,
+    lock_script: &Script,
+    cell_deps: Vec<CellDep>,
+    input_data: Bytes,
+    since: u64,
+    outputs: Vec<(Script, u64, Bytes)>,
+) -> TransactionView {
+    let inputs: Vec<CellInput> = [1000u64, 300]
+        .iter()
+        .map(|capacity| {
+            let out_point = context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(capacity.pack())
+                    .lock(lock_script.clone())
+                    .build(),
+                input_data.clone(),
+            );
+            CellInput::new_builder().previous_output(out_point).since(since.pack()).build()
+        })
+        .collect();
+    let outputs_data: Vec<Bytes> = outputs.iter().map(|(_, _, data)| data.clone()).collect();
+    let outputs: Vec<CellOutput> = outputs
+        .into_iter()
+        .map(|(lock, capacity, _)| CellOutput::new_builder().capacity(capacity.pack()).lock(lock).build())
+        .collect();
+    let witnesses = vec![Bytes::new(); inputs.len()];
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_deps(cell_deps)
+        .witnesses(witnesses.pack())
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_rotation_grace() {
+    let (_, old_public_key) = generate_random_key(1024);
+    let (_, old_public_key_hash) = compute_pub_key_hash(&old_public_key, ISO9796_2_MD_SHA1, false);
+    let (new_private_key, new_public_key) = generate_random_key(1024);
+    let (_, new_public_key_hash) = compute_pub_key_hash(&new_public_key, ISO9796_2_MD_SHA1, false);
+    let (other_private_key, other_public_key) = generate_random_key(1024);
+
+    // the old passport designated the new one as its successor
+    let mut grace = PASSPORT_EXPIRY.to_le_bytes().to_vec();
+    grace.extend_from_slice(&GRACE_CLAIM_DELAY.to_le_bytes());
+    grace.extend_from_slice(&new_public_key_hash);
+    let args = [old_public_key_hash.clone(), vec![FLAG_ROTATION_GRACE], grace.clone()].concat();
+    let claimed_args = [
+        old_public_key_hash.clone(),
+        vec![0x80 | FLAG_ROTATION_GRACE, 0x80, FLAG_GRACE_CLAIMED],
+        grace,
+    ]
+    .concat();
+
+    // (claimed inputs, input since, claimed outputs, signed by the successor, error)
+    let cases = vec![
+        // the successor requests the claim after the expiry
+        (false, PASSPORT_EXPIRY, true, true, None),
+        (false, PASSPORT_EXPIRY - 1, true, true, Some(SINCE_NOT_SATISFIED)),
+        (false, PASSPORT_EXPIRY, false, true, Some(GRACE_CLAIM_OUTPUT_MISMATCH)),
+        (false, PASSPORT_EXPIRY, true, false, Some(WRONG_PUB_KEY)),
+        // the successor spends the claimed cells after the delay
+        (true, GRACE_CLAIM_DELAY, false, true, None),
+        (true, GRACE_CLAIM_DELAY - 1, false, true, Some(SINCE_NOT_SATISFIED)),
+        (true, PASSPORT_EXPIRY, false, true, Some(SINCE_MISMATCH)),
+        (true, GRACE_CLAIM_DELAY, false, false, Some(WRONG_PUB_KEY)),
+    ];
+    for (claimed, since, claimed_outputs, successor, error) in cases {
+        let mut context = Context::default();
+        let (lock_script, cell_deps) = deploy_passport_lock(&mut context, args.clone());
+        let claimed_lock_script = lock_script
+            .clone()
+            .as_builder()
+            .args(Bytes::from(claimed_args.clone()).pack())
+            .build();
+        let new_lock_script = lock_script
+            .clone()
+            .as_builder()
+            .args(Bytes::from(new_public_key_hash.clone()).pack())
+            .build();
+        let input_lock = if claimed { &claimed_lock_script } else { &lock_script };
+        let output = if claimed_outputs {
+            (claimed_lock_script.clone(), 1300, Bytes::new())
+        } else {
+            (new_lock_script, 1300, Bytes::new())
+        };
+        let tx = build_spend_tx(&mut context, input_lock, cell_deps, Bytes::new(), since, vec![output]);
+        let tx = if successor {
+            sign_tx(tx, &new_private_key, &new_public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign)
+        } else {
+            sign_tx(tx, &other_private_key, &other_public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign)
+        };
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+
+    // another passport has no claim without the grace mode
+    let mut context = Context::default();
+    let (lock_script, cell_deps) = deploy_passport_lock(&mut context, old_public_key_hash);
+    let outputs = vec![(lock_script.clone(), 1300, Bytes::new())];
+    let tx = build_spend_tx(&mut context, &lock_script, cell_deps, Bytes::new(), PASSPORT_EXPIRY, outputs);
+    let tx = sign_tx(tx, &new_private_key, &new_public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(WRONG_PUB_KEY).input_lock_script(script_cell_index)
+    );
+}
+
+// The args of the passport lock accept payments of at least 10^2 shannons or 10^1 UDT, the
+// payer and the UDT type are always-success scripts
+fn build_acp_test_context(args: Vec<u8>) -> (Context, Script, Vec<CellDep>, Script) {
+    let mut context = Context::default();
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("script");
+    let (lock_script, mut cell_deps) = deploy_passport_lock(&mut context, args);
+    cell_deps.push(CellDep::new_builder().out_point(always_success_out_point).build());
+    (context, lock_script, cell_deps, always_success)
+}
+
+// Pays to a cell of the lock, given as (capacity, UDT amount), from a cell of the payer.
+// With udt, the cells of the lock have the always-success type and hold a UDT amount.
+fn build_acp_tx(
+    context: &mut Context,
+    lock_script: &Script,
+    cell_deps: Vec<CellDep>,
+    always_success: &Script,
+    udt: bool,
+    input: (u64, u128),
+    outputs: Vec<(u64, u128)>,
+) -> TransactionView {
+    let type_script = if udt { Some(always_success.clone()) } else { None };
+    let udt_data = |amount: u128| {
+        if udt {
+            Bytes::from(amount.to_le_bytes().to_vec())
+        } else {
+            Bytes::new()
+        }
+    };
+    let passport_cell = |capacity: u64| {
+        CellOutput::new_builder()
+            .capacity(capacity.pack())
+            .lock(lock_script.clone())
+            .type_(type_script.clone().pack())
+            .build()
+    };
+    let payer = CellOutput::new_builder()
+        .capacity(10_000u64.pack())
+        .lock(always_success.clone())
+        .build();
+    let inputs: Vec<CellInput> = vec![
+        context.create_cell(passport_cell(input.0), udt_data(input.1)),
+        context.create_cell(payer.clone(), Bytes::new()),
+    ]
+    .into_iter()
+    .map(|out_point| CellInput::new_builder().previous_output(out_point).build())
+    .collect();
+    let mut outputs_data: Vec<Bytes> = outputs.iter().map(|(_, amount)| udt_data(*amount)).collect();
+    let mut outputs: Vec<CellOutput> = outputs.into_iter().map(|(capacity, _)| passport_cell(capacity)).collect();
+    outputs.push(payer.as_builder().capacity(9_000u64.pack()).build());
+    outputs_data.push(Bytes::new());
+
+    // no witness for the passport lock
+    let tx = TransactionBuilder::default()
+        .inputs(inputs)
+        .outputs(outputs)
+        .outputs_data(outputs_data.pack())
+        .cell_deps(cell_deps)
+        .witnesses(vec![Bytes::new(); 2].pack())
+        .build();
+    context.complete_tx(tx)
+}
+
+#[test]
+fn test_anyone_can_pay() {
+    let (_, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+
+    // (udt, input, outputs, error)
+    let cases = vec![
+        (false, (1000, 0), vec![(1100, 0)], None),
+        (false, (1000, 0), vec![(1099, 0)], Some(PAYMENT_TOO_SMALL)),
+        (false, (1000, 0), vec![], Some(PAYMENT_OUTPUT_MISMATCH)),
+        (false, (1000, 0), vec![(600, 0), (600, 0)], Some(PAYMENT_OUTPUT_MISMATCH)),
+        (true, (1000, 50), vec![(1000, 60)], None),
+        (true, (1000, 50), vec![(1100, 50)], None),
+        (true, (1000, 50), vec![(1000, 59)], Some(PAYMENT_TOO_SMALL)),
+        (true, (1000, 50), vec![(2000, 49)], Some(PAYMENT_TOO_SMALL)),
+    ];
+    for (udt, input, outputs, error) in cases {
+        let args = [public_key_hash.clone(), vec![FLAG_ACP, 2, 1]].concat();
+        let (mut context, lock_script, cell_deps, always_success) = build_acp_test_context(args);
+        let tx = build_acp_tx(&mut context, &lock_script, cell_deps, &always_success, udt, input, outputs);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_anyone_can_pay_state() {
+    let (_, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let acp = vec![2u8, 1];
+    let guardians = [vec![1u8; 20], RECOVERY_DELAY.to_le_bytes().to_vec()].concat();
+    let grace = [
+        PASSPORT_EXPIRY.to_le_bytes().to_vec(),
+        GRACE_CLAIM_DELAY.to_le_bytes().to_vec(),
+        vec![2u8; 20],
+    ]
+    .concat();
+    let limit = [TX_SPENDING_LIMIT.to_le_bytes(), TX_SPENDING_LIMIT_DELAY.to_le_bytes()].concat();
+
+    // the payment may not move the cells to the state of another unlock path:
+    // (args, args of the paid output, data of the paid output is the lock hash, udt, error)
+    let cases = vec![
+        // a pending guardian recovery
+        (
+            [public_key_hash.clone(), vec![FLAG_GUARDIANS | FLAG_ACP], guardians.clone(), acp.clone()].concat(),
+            Some(
+                [
+                    public_key_hash.clone(),
+                    vec![0x80 | FLAG_GUARDIANS | FLAG_ACP, 0x80, FLAG_RECOVERY_PENDING],
+                    guardians,
+                    acp.clone(),
+                    vec![3u8; 32],
+                ]
+                .concat(),
+            ),
+            false,
+            false,
+            Some(PAYMENT_OUTPUT_MISMATCH),
+        ),
+        // a claimed rotation grace
+        (
+            [public_key_hash.clone(), vec![FLAG_ROTATION_GRACE | FLAG_ACP], grace.clone(), acp.clone()].concat(),
+            Some(
+                [
+                    public_key_hash.clone(),
+                    vec![0x80 | FLAG_ROTATION_GRACE | FLAG_ACP, 0x80, FLAG_GRACE_CLAIMED],
+                    grace,
+                    acp.clone(),
+                ]
+                .concat(),
+            ),
+            false,
+            false,
+            Some(PAYMENT_OUTPUT_MISMATCH),
+        ),
+        // pending cells of a spending limit, whose data is the lock hash
+        (
+            [public_key_hash.clone(), vec![0x80 | FLAG_ACP, FLAG_TX_SPENDING_LIMIT], acp.clone(), limit.clone()].concat(),
+            None,
+            true,
+            false,
+            Some(PAYMENT_OUTPUT_MISMATCH),
+        ),
+        (
+            [public_key_hash.clone(), vec![0x80 | FLAG_ACP, FLAG_TX_SPENDING_LIMIT], acp.clone(), limit].concat(),
+            None,
+            false,
+            false,
+            None,
+        ),
+        // the data beyond the UDT amount
+        (
+            [public_key_hash.clone(), vec![FLAG_ACP], acp].concat(),
+            None,
+            true,
+            true,
+            Some(PAYMENT_OUTPUT_MISMATCH),
+        ),
+    ];
+    for (args, output_args, mark_output, udt, error) in cases {
+        let (mut context, lock_script, cell_deps, always_success) = build_acp_test_context(args);
+        let tx = build_acp_tx(&mut context, &lock_script, cell_deps, &always_success, udt, (1000, 50), vec![(1100, 60)]);
+        let mut outputs: Vec<CellOutput> = tx.outputs().into_iter().collect();
+        let mut outputs_data: Vec<Bytes> = tx.outputs_data().into_iter().map(|data| data.unpack()).collect();
+        if let Some(output_args) = output_args {
+            let lock = lock_script.clone().as_builder().args(Bytes::from(output_args).pack()).build();
+            outputs[0] = outputs[0].clone().as_builder().lock(lock).build();
+        }
+        if mark_output {
+            let lock_hash: [u8; 32] = lock_script.calc_script_hash().unpack();
+            outputs_data[0] = [outputs_data[0].to_vec(), lock_hash.to_vec()].concat().into();
+        }
+        let tx = tx
+            .as_advanced_builder()
+            .set_outputs(outputs)
+            .set_outputs_data(outputs_data.into_iter().map(|data| data.pack()).collect())
+            .build();
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_anyone_can_pay_signed() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, mut public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    public_key_hash.extend_from_slice(&[FLAG_ACP, 2, 1]);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // the passport signature spends the cells freely, though the outputs are no payment
+    let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+    // run
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+fn sighash_header(public_key: &PKey<Public>) -> [u8; 4] {
+    [RSA_ALGORITHM_ID, get_key_size(public_key), PKCS1_V15_PADDING, ISO9796_2_MD_SHA256]
+}
+
+// Signs the two passport inputs with a PassportLockWitnessV2 through the SDK signer
+fn sign_tx_sighash(
+    tx: TransactionView,
+    private_key: &PKey<Private>,
+    public_key: &PKey<Public>,
+    sighash_type: u8,
+) -> Result<TransactionView, signer::Error> {
+    let header = sighash_header(public_key);
+    let (rsa_info, _) = compute_rsa_pub_key_hash(public_key, header, false);
+    signer::sign_group(tx, &[0, 1], sighash_type, |message| {
+        let signature = match message {
+            Some(message) => pkcs1_sign(private_key, ISO9796_2_MD_SHA256, message),
+            None => vec![0u8; get_key_len(public_key)],
+        };
+        signer::witness_lock(header, &rsa_info[4..], &[signature], sighash_type)
+    })
+}
+
+// Another party adds an always-success input, and an output if with_output
+fn complete_by_other_party(context: &mut Context, tx: TransactionView, with_output: bool) -> TransactionView {
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let always_success = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("script");
+    let cell = CellOutput::new_builder()
+        .capacity(2000u64.pack())
+        .lock(always_success)
+        .build();
+    let out_point = context.create_cell(cell.clone(), Bytes::new());
+    let mut builder = tx
+        .as_advanced_builder()
+        .input(CellInput::new_builder().previous_output(out_point).build())
+        .cell_dep(CellDep::new_builder().out_point(always_success_out_point).build());
+    if with_output {
+        builder = builder.output(cell).output_data(Bytes::new().pack());
+    }
+    builder.build()
+}
+
+#[test]
+fn test_sighash_modes() {
+    let (private_key, public_key) = generate_random_key(2048);
+    let (_, public_key_hash) = compute_rsa_pub_key_hash(&public_key, sighash_header(&public_key), false);
+
+    // (sighash type, other party adds an input, and an output, error)
+    let cases = vec![
+        (signer::SIGHASH_ALL, false, false, None),
+        (signer::SIGHASH_ALL, true, false, Some(RSA_VERIFY_ERROR)),
+        (signer::SIGHASH_ALL | signer::SIGHASH_ANYONECANPAY, true, false, None),
+        (signer::SIGHASH_ALL | signer::SIGHASH_ANYONECANPAY, true, true, Some(RSA_VERIFY_ERROR)),
+        (signer::SIGHASH_SINGLE, false, false, None),
+        (signer::SIGHASH_SINGLE, true, true, Some(RSA_VERIFY_ERROR)),
+        (signer::SIGHASH_SINGLE | signer::SIGHASH_ANYONECANPAY, true, true, None),
+    ];
+    for (sighash_type, with_input, with_output, error) in cases {
+        let (mut context, tx) = build_test_context(public_key_hash.clone());
+
+        // sign, then complete
+        let tx = sign_tx_sighash(tx, &private_key, &public_key, sighash_type).expect("sign");
+        let tx = if with_input {
+            complete_by_other_party(&mut context, tx, with_output)
+        } else {
+            tx
+        };
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_sighash_single_output_changed() {
+    let (private_key, public_key) = generate_random_key(2048);
+    let (_, public_key_hash) = compute_rsa_pub_key_hash(&public_key, sighash_header(&public_key), false);
+    let (context, tx) = build_test_context(public_key_hash);
+
+    // the paired outputs are covered
+    let sighash_type = signer::SIGHASH_SINGLE | signer::SIGHASH_ANYONECANPAY;
+    let tx = sign_tx_sighash(tx, &private_key, &public_key, sighash_type).expect("sign");
+    let outputs: Vec<CellOutput> = tx
+        .outputs()
+        .into_iter()
+        .map(|output| output.as_builder().capacity(650u64.pack()).build())
+        .collect();
+    let tx = tx.as_advanced_builder().set_outputs(outputs).build();
+
+    // run
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    let script_cell_index = 0;
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(RSA_VERIFY_ERROR).input_lock_script(script_cell_index)
+    );
+}
+
+#[test]
+fn test_invalid_sighash_type() {
+    let (_, public_key) = generate_random_key(2048);
+    let header = sighash_header(&public_key);
+    let (rsa_info, public_key_hash) = compute_rsa_pub_key_hash(&public_key, header, false);
+    let zero_signature = vec![0u8; get_key_len(&public_key)];
+
+    // (sighash type, output count, host error, contract error)
+    let cases = vec![
+        (0x02, 2, signer::Error::UnsupportedSighashType, UNSUPPORTED_SIGHASH_TYPE),
+        (0x81 | 0x04, 2, signer::Error::UnsupportedSighashType, UNSUPPORTED_SIGHASH_TYPE),
+        (signer::SIGHASH_SINGLE, 1, signer::Error::OutputMissing, SIGHASH_OUTPUT_MISSING),
+    ];
+    for (sighash_type, output_count, host_error, error) in cases {
+        let (context, tx) = build_test_context(public_key_hash.clone());
+        let outputs: Vec<CellOutput> = tx.outputs().into_iter().take(output_count).collect();
+        let outputs_data: Vec<packed::Bytes> = tx.outputs_data().into_iter().take(output_count).collect();
+        let tx = tx
+            .as_advanced_builder()
+            .set_outputs(outputs)
+            .set_outputs_data(outputs_data)
+            .build();
+
+        // the host signer refuses, the contract fails before checking the signature
+        let err = signer::signing_message(&tx, &[0, 1], sighash_type, 0).unwrap_err();
+        assert_eq!(err, host_error);
+        let tx = sign_tx_message(tx, |_| {
+            signer::witness_lock(header, &rsa_info[4..], &[zero_signature.clone()], sighash_type)
+        });
+
+        // run
+        let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+        let script_cell_index = 0;
+        assert_error_eq!(
+            err,
+            ScriptError::ValidationFailure(error).input_lock_script(script_cell_index)
+        );
+    }
+}
+
+#[test]
+fn test_tx_spending_limit() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let mut args = public_key_hash;
+    args.extend_from_slice(&[0x80, FLAG_TX_SPENDING_LIMIT]);
+    args.extend_from_slice(&TX_SPENDING_LIMIT.to_le_bytes());
+    args.extend_from_slice(&TX_SPENDING_LIMIT_DELAY.to_le_bytes());
+
+    // (pending inputs, input since, outputs of the passport lock, outputs of another lock, error)
+    let cases = vec![
+        (false, 0, vec![800], vec![500], None),
+        (false, 0, vec![300, 500], vec![500], None),
+        (false, 0, vec![799], vec![500], Some(SPENDING_LIMIT_EXCEEDED)),
+        (false, 0, vec![600, 200], vec![250, 250], None),
+        (false, 0, vec![], vec![1300], Some(SPENDING_LIMIT_EXCEEDED)),
+        (false, 0, vec![1300], vec![], None),
+        (false, TX_SPENDING_LIMIT_DELAY, vec![], vec![1300], Some(SPENDING_LIMIT_EXCEEDED)),
+        (true, TX_SPENDING_LIMIT_DELAY, vec![], vec![1300], None),
+        (true, TX_SPENDING_LIMIT_DELAY - 1, vec![], vec![1300], Some(SINCE_NOT_SATISFIED)),
+        (true, 0, vec![1000], vec![300], None),
+    ];
+    for (pending, since, passport_outputs, other_outputs, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let other_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let (lock_script, cell_deps) = deploy_passport_lock(&mut context, args.clone());
+        let script_hash: [u8; 32] = lock_script.calc_script_hash().unpack();
+        let input_data = if pending {
+            Bytes::from(script_hash.to_vec())
+        } else {
+            Bytes::new()
+        };
+        let outputs = passport_outputs
+            .into_iter()
+            .map(|capacity| (lock_script.clone(), capacity, Bytes::new()))
+            .chain(other_outputs.into_iter().map(|capacity| (other_lock.clone(), capacity, Bytes::new())))
+            .collect();
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, input_data, since, outputs);
+        let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_epoch_spending_limit() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let state_type = Script::new_builder()
+        .code_hash(delegation::TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::from(vec![9u8; 32]).pack())
+        .build();
+    let state_type_hash: [u8; 32] = state_type.calc_script_hash().unpack();
+    let mut args = public_key_hash;
+    args.extend_from_slice(&[0x80, 0x80, FLAG_EPOCH_SPENDING_LIMIT]);
+    args.extend_from_slice(&TX_SPENDING_LIMIT.to_le_bytes());
+    args.extend_from_slice(&TX_SPENDING_LIMIT_DELAY.to_le_bytes());
+    args.extend_from_slice(&state_type_hash);
+
+    // (spent in the state cell, since of the state input and spent in the new state cell,
+    // pending inputs, input since, outputs to another lock, error)
+    let cases = vec![
+        (Some((0u64, 0, 300u64)), false, 0, 300, None),
+        (Some((200, 0, 500)), false, 0, 300, None),
+        (Some((300, 0, 600)), false, 0, 300, Some(SPENDING_LIMIT_EXCEEDED)),
+        (Some((300, epoch_since(SPENDING_STATE_EPOCH + 1, 0, 1), 300)), false, 0, 300, None),
+        (Some((300, epoch_since(SPENDING_STATE_EPOCH, 1, 2), 600)), false, 0, 300, Some(SPENDING_LIMIT_EXCEEDED)),
+        (Some((300, epoch_since(SPENDING_STATE_EPOCH + 1, 0, 1), 0)), false, 0, 300, Some(SPENDING_STATE_MISMATCH)),
+        (Some((0, 0, 0)), false, 0, 300, Some(SPENDING_STATE_MISMATCH)),
+        (None, false, 0, 300, Some(SPENDING_LIMIT_EXCEEDED)),
+        (None, false, 0, 0, None),
+        (None, true, TX_SPENDING_LIMIT_DELAY, 1300, None),
+        (None, true, TX_SPENDING_LIMIT_DELAY - 1, 1300, Some(SINCE_NOT_SATISFIED)),
+    ];
+    for (state, pending, since, other_capacity, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let other_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let (lock_script, cell_deps) = deploy_passport_lock(&mut context, args.clone());
+        let script_hash: [u8; 32] = lock_script.calc_script_hash().unpack();
+        let input_data = if pending {
+            Bytes::from(script_hash.to_vec())
+        } else {
+            Bytes::new()
+        };
+        let outputs = vec![
+            (lock_script.clone(), 1300 - other_capacity, Bytes::new()),
+            (other_lock, other_capacity, Bytes::new()),
+        ];
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, input_data, since, outputs);
+
+        // the state cell was created in a block of the state epoch
+        let tx = match state {
+            Some((spent, state_since, new_spent)) => {
+                let state_cell = CellOutput::new_builder()
+                    .capacity(100u64.pack())
+                    .lock(lock_script.clone())
+                    .type_(Some(state_type.clone()).pack())
+                    .build();
+                let out_point = context.create_cell(state_cell.clone(), Bytes::from(spent.to_le_bytes().to_vec()));
+                let header = HeaderBuilder::default()
+                    .epoch(EpochNumberWithFraction::new(SPENDING_STATE_EPOCH, 0, 1).pack())
+                    .build();
+                context.insert_header(header.clone());
+                context.link_cell_with_block(out_point.clone(), header.hash(), 0);
+                tx.as_advanced_builder()
+                    .input(CellInput::new_builder().previous_output(out_point).since(state_since.pack()).build())
+                    .output(state_cell)
+                    .output_data(Bytes::from(new_spent.to_le_bytes().to_vec()).pack())
+                    .witness(Bytes::new().pack())
+                    .header_dep(header.hash())
+                    .build()
+            }
+            None => tx,
+        };
+        let tx = sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_output_whitelist() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+
+    // (whitelisted destinations, proven destinations, error)
+    let cases = vec![
+        (vec![1u8], vec![1u8], None),
+        (vec![0, 4], vec![0, 4], None),
+        (vec![], vec![], None),
+        (vec![5], vec![2], Some(OUTPUT_NOT_WHITELISTED)),
+        (vec![1, 2], vec![1], Some(OUTPUT_NOT_WHITELISTED)),
+        (vec![1], vec![1, 2], Some(WITNESS_TRAILING_BYTES)),
+    ];
+    for (destinations, proven, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let allowed: Vec<Script> = (0..5u8)
+            .map(|i| {
+                context
+                    .build_script(&always_success_out_point, Bytes::from(vec![i]))
+                    .expect("script")
+            })
+            .collect();
+        let allowed_hashes: Vec<[u8; 32]> = allowed.iter().map(|lock| lock.calc_script_hash().unpack()).collect();
+        let other_lock = context
+            .build_script(&always_success_out_point, Bytes::from(vec![5u8]))
+            .expect("script");
+
+        let mut args = public_key_hash.clone();
+        args.extend_from_slice(&[0x80, FLAG_WHITELIST]);
+        args.extend_from_slice(&whitelist::merkle_root(&allowed_hashes));
+        let (lock_script, cell_deps) = deploy_passport_lock(&mut context, args);
+
+        // the change goes back to the passport lock, which needs no proof
+        let destination = |i: u8| if i < 5 { allowed[i as usize].clone() } else { other_lock.clone() };
+        let mut outputs = vec![(lock_script.clone(), 300, Bytes::new())];
+        for i in destinations {
+            outputs.push((destination(i), 500, Bytes::new()));
+        }
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, Bytes::new(), 0, outputs);
+
+        // the proofs of the outputs wrap the witness lock
+        let proofs: Vec<Vec<[u8; 32]>> = proven
+            .iter()
+            .map(|i| whitelist::merkle_proof(&allowed_hashes, *i as usize))
+            .collect();
+        let tx = sign_tx_with_lock(
+            tx,
+            get_key_len(&public_key),
+            SUB_SIGNATURE_COUNT,
+            |message| iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, message),
+            |signatures| {
+                let mut lock = signatures.concat();
+                lock.extend_from_slice(&rsa_info);
+                whitelist::witness_lock(&proofs, &lock)
+            },
+        );
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_vault() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let recovery_key = Generator::random_privkey();
+    let vault_args = |flags: u8| {
+        let mut args = public_key_hash.clone();
+        args.extend_from_slice(&[0x80, flags]);
+        args.extend_from_slice(&compute_secp256k1_pub_key_hash(&recovery_key));
+        args.extend_from_slice(&UNVAULTING_DELAY.to_le_bytes());
+        args
+    };
+
+    // (unvaulting inputs, input since, signed by the recovery key, outputs to the vault,
+    // outputs to the unvaulting lock, outputs to another lock, error)
+    let cases = vec![
+        (false, 0, false, vec![], vec![1300], vec![], None),
+        (false, 0, false, vec![300], vec![1000], vec![], None),
+        (false, 0, false, vec![300], vec![900], vec![100], Some(VAULT_OUTPUT_MISMATCH)),
+        (false, 0, false, vec![], vec![800], vec![500], Some(VAULT_OUTPUT_MISMATCH)),
+        (false, UNVAULTING_DELAY, false, vec![], vec![], vec![1300], Some(VAULT_OUTPUT_MISMATCH)),
+        (true, UNVAULTING_DELAY, false, vec![], vec![], vec![1300], None),
+        (true, UNVAULTING_DELAY - 1, false, vec![], vec![], vec![1300], Some(SINCE_NOT_SATISFIED)),
+        (true, 0, true, vec![1300], vec![], vec![], None),
+        (true, 0, true, vec![], vec![1300], vec![], Some(VAULT_OUTPUT_MISMATCH)),
+        (true, 0, true, vec![800], vec![], vec![500], Some(VAULT_OUTPUT_MISMATCH)),
+    ];
+    for (unvaulting, since, recovery, vault_outputs, unvaulting_outputs, other_outputs, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let other_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let (vault_lock, cell_deps) = deploy_passport_lock(&mut context, vault_args(FLAG_VAULT));
+        let unvaulting_lock = vault_lock
+            .clone()
+            .as_builder()
+            .args(Bytes::from(vault_args(FLAG_VAULT | FLAG_UNVAULTING)).pack())
+            .build();
+        let input_lock = if unvaulting { &unvaulting_lock } else { &vault_lock };
+        let outputs = vault_outputs
+            .into_iter()
+            .map(|capacity| (vault_lock.clone(), capacity, Bytes::new()))
+            .chain(unvaulting_outputs.into_iter().map(|capacity| (unvaulting_lock.clone(), capacity, Bytes::new())))
+            .chain(other_outputs.into_iter().map(|capacity| (other_lock.clone(), capacity, Bytes::new())))
+            .collect();
+        let tx = build_spend_tx(&mut context, input_lock, cell_deps, Bytes::new(), since, outputs);
+        let tx = if recovery {
+            sign_tx_secp256k1(tx, &recovery_key)
+        } else {
+            sign_tx(tx, &private_key, &public_key, ISO9796_2_MD_SHA1, false, iso9796_2_sign)
+        };
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_revocation() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let mut passport_hash = [0u8; 20];
+    passport_hash.copy_from_slice(&public_key_hash);
+    let other_hashes: Vec<[u8; 20]> = [0x00u8, 0x7F, 0xFF].iter().map(|byte| [*byte; 20]).collect();
+
+    // (registry cells, passport revoked, position offset, error)
+    let cases = vec![
+        (1, false, 0, None),
+        (1, false, 1, Some(PASSPORT_REVOKED)),
+        (1, true, 0, Some(PASSPORT_REVOKED)),
+        (1, true, 1, Some(PASSPORT_REVOKED)),
+        (0, false, 0, Some(REVOCATION_REGISTRY_MISSING)),
+        (2, false, 0, Some(REVOCATION_REGISTRY_MISSING)),
+    ];
+    for (registry_count, revoked, position_offset, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let registry_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let registry_bin: Bytes = Loader::default().load_binary("ckb-passport-revocation-registry");
+        let registry_out_point = context.deploy_cell(registry_bin);
+        let registry_type = context
+            .build_script(&registry_out_point, registry_lock.calc_script_hash().as_bytes())
+            .expect("script");
+        let mut revoked_hashes = other_hashes.clone();
+        if revoked {
+            revoked_hashes.push(passport_hash);
+        }
+        let registry_data = revocation::registry_data(&revoked_hashes);
+
+        let mut args = public_key_hash.clone();
+        args.extend_from_slice(&[0x80, FLAG_REVOCATION]);
+        args.extend_from_slice(registry_type.calc_script_hash().as_slice());
+        let (lock_script, mut cell_deps) = deploy_passport_lock(&mut context, args);
+        for _ in 0..registry_count {
+            let registry_out_point = context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(registry_lock.clone())
+                    .type_(Some(registry_type.clone()).pack())
+                    .build(),
+                Bytes::from(registry_data.clone()),
+            );
+            cell_deps.push(CellDep::new_builder().out_point(registry_out_point).build());
+        }
+        let outputs = vec![(registry_lock.clone(), 1300, Bytes::new())];
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, Bytes::new(), 0, outputs);
+
+        // a revoked passport claims the position of its own entry
+        let position = revocation::position(&registry_data, &passport_hash).unwrap_or_else(|| {
+            revoked_hashes.iter().filter(|hash| **hash < passport_hash).count() as u32
+        });
+        let positions = vec![position + position_offset];
+        let tx = sign_tx_with_lock(
+            tx,
+            get_key_len(&public_key),
+            SUB_SIGNATURE_COUNT,
+            |message| iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, message),
+            |signatures| {
+                let mut lock = signatures.concat();
+                lock.extend_from_slice(&rsa_info);
+                revocation::witness_lock(&positions, &lock)
+            },
+        );
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+fn create_registry_cell(context: &mut Context, registry_data: &[u8]) -> CellDep {
+    let registry_bin: Bytes = Loader::default().load_binary("ckb-passport-pubkey-registry");
+    let registry_out_point = context.deploy_cell(registry_bin);
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    let lock = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("script");
+    let registry_type = context
+        .build_script(&registry_out_point, Bytes::from(blake160(registry_data).to_vec()))
+        .expect("script");
+    let out_point = context.create_cell(
+        CellOutput::new_builder()
+            .capacity(1000u64.pack())
+            .lock(lock)
+            .type_(Some(registry_type).pack())
+            .build(),
+        Bytes::from(registry_data.to_vec()),
+    );
+    CellDep::new_builder().out_point(out_point).build()
+}
+
+#[test]
+fn test_pubkey_registry() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (_, other_public_key) = generate_random_key(1024);
+    let (other_rsa_info, _) = compute_pub_key_hash(&other_public_key, ISO9796_2_MD_SHA1, false);
+
+    // (registry data, error)
+    let cases = vec![(rsa_info, None), (other_rsa_info, Some(WRONG_PUB_KEY))];
+    for (registry_data, error) in cases {
+        let mut context = Context::default();
+        let (lock_script, mut cell_deps) = deploy_passport_lock(&mut context, public_key_hash.clone());
+        let pub_key_dep = cell_deps.len() as u32;
+        cell_deps.push(create_registry_cell(&mut context, &registry_data));
+        let outputs = vec![(lock_script.clone(), 1300, Bytes::new())];
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, Bytes::new(), 0, outputs);
+
+        // the witness lock carries the signatures only
+        let tx = sign_tx_with_lock(
+            tx,
+            get_key_len(&public_key),
+            SUB_SIGNATURE_COUNT,
+            |message| iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, message),
+            |signatures| signer::registry_witness_lock(pub_key_dep, &signatures, signer::SIGHASH_ALL),
+        );
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_pubkey_registry_creation() {
+    let (_, public_key) = generate_random_key(1024);
+    let (rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let mut truncated_rsa_info = rsa_info.clone();
+    truncated_rsa_info.pop();
+    let (_, other_public_key_hash) = compute_pub_key_hash(&generate_random_key(1024).1, ISO9796_2_MD_SHA1, false);
+
+    // (registry type args, registry data, error)
+    let cases = vec![
+        (public_key_hash, rsa_info.clone(), None),
+        (other_public_key_hash, rsa_info, Some(REGISTRY_PUB_KEY_HASH_MISMATCH)),
+        (blake160(&truncated_rsa_info).to_vec(), truncated_rsa_info, Some(REGISTRY_INVALID_PUB_KEY)),
+    ];
+    for (registry_args, registry_data, error) in cases {
+        let mut context = Context::default();
+        let registry_bin: Bytes = Loader::default().load_binary("ckb-passport-pubkey-registry");
+        let registry_out_point = context.deploy_cell(registry_bin);
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let registry_type = context
+            .build_script(&registry_out_point, Bytes::from(registry_args))
+            .expect("script");
+        let input_out_point = context.create_cell(
+            CellOutput::new_builder().capacity(1000u64.pack()).lock(lock.clone()).build(),
+            Bytes::new(),
+        );
+        let tx = TransactionBuilder::default()
+            .input(CellInput::new_builder().previous_output(input_out_point).build())
+            .output(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(lock)
+                    .type_(Some(registry_type).pack())
+                    .build(),
+            )
+            .output_data(Bytes::from(registry_data).pack())
+            .build();
+        let tx = context.complete_tx(tx);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).output_type_script(0)));
+    }
+}
+
+#[test]
+fn test_revocation_registry_update() {
+    let revoked: Vec<[u8; 20]> = [0x00u8, 0x7F, 0xFF].iter().map(|byte| [*byte; 20]).collect();
+    let sorted = revocation::registry_data(&revoked);
+    let unsorted = revocation::registry_data(&[revoked[1], revoked[0], revoked[2]]);
+    let duplicated = revocation::registry_data(&[revoked[0], revoked[1], revoked[1]]);
+    let mut truncated = sorted.clone();
+    truncated.pop();
+
+    // (updater input, registry data, error)
+    let cases = vec![
+        (true, sorted.clone(), None),
+        (true, Vec::new(), None),
+        (false, sorted, Some(REVOCATION_UPDATER_NOT_PRESENT)),
+        (true, unsorted, Some(REVOCATION_REVOKED_NOT_SORTED)),
+        (true, duplicated, Some(REVOCATION_REVOKED_NOT_SORTED)),
+        (true, truncated, Some(REVOCATION_ENCODING)),
+    ];
+    for (updater_input, registry_data, error) in cases {
+        let mut context = Context::default();
+        let registry_bin: Bytes = Loader::default().load_binary("ckb-passport-revocation-registry");
+        let registry_out_point = context.deploy_cell(registry_bin);
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let updater_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let other_lock = context
+            .build_script(&always_success_out_point, Bytes::from(vec![1u8]))
+            .expect("script");
+        let registry_type = context
+            .build_script(&registry_out_point, updater_lock.calc_script_hash().as_bytes())
+            .expect("script");
+        let input_lock = if updater_input { updater_lock.clone() } else { other_lock };
+        let input_out_point = context.create_cell(
+            CellOutput::new_builder().capacity(1000u64.pack()).lock(input_lock).build(),
+            Bytes::new(),
+        );
+        let tx = TransactionBuilder::default()
+            .input(CellInput::new_builder().previous_output(input_out_point).build())
+            .output(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(updater_lock)
+                    .type_(Some(registry_type).pack())
+                    .build(),
+            )
+            .output_data(Bytes::from(registry_data).pack())
+            .build();
+        let tx = context.complete_tx(tx);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).output_type_script(0)));
+    }
+}
+
+// The delegation cell is a type id cell of the passport lock, the passport signs the
+// certificate as the legacy witness lock of four ISO 9796-2 signatures
+fn build_delegation_cell(
+    lock_script: &Script,
+    private_key: &PKey<Private>,
+    public_key: &PKey<Public>,
+    session_key: &Privkey,
+    type_id: [u8; 32],
+    certificate_type_id: [u8; 32],
+    spend_cap: Option<u64>,
+) -> (CellOutput, Bytes) {
+    let (rsa_info, _) = compute_pub_key_hash(public_key, ISO9796_2_MD_SHA1, false);
+    let mut session_pub_key_hash = [0u8; 20];
+    session_pub_key_hash.copy_from_slice(&compute_secp256k1_pub_key_hash(session_key));
+    let certificate = delegation::certificate(session_pub_key_hash, certificate_type_id, DELEGATION_EXPIRY, spend_cap);
+    let message = delegation::signing_message(&certificate);
+    let mut passport_lock: Vec<u8> = message
+        .chunks(message.len() / SUB_SIGNATURE_COUNT)
+        .map(|sub_message| iso9796_2_sign(private_key, ISO9796_2_MD_SHA1, sub_message))
+        .collect::<Vec<_>>()
+        .concat();
+    passport_lock.extend_from_slice(&rsa_info);
+
+    let type_script = Script::new_builder()
+        .code_hash(delegation::TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::from(type_id.to_vec()).pack())
+        .build();
+    let output = CellOutput::new_builder()
+        .capacity(1000u64.pack())
+        .lock(lock_script.clone())
+        .type_(Some(type_script).pack())
+        .build();
+    (output, Bytes::from(delegation::cell_data(certificate, &passport_lock)))
+}
+
+#[test]
+fn test_session_key() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (other_private_key, other_public_key) = generate_random_key(1024);
+    let session_key = Generator::random_privkey();
+    let mut args = public_key_hash;
+    args.extend_from_slice(&[0x80, FLAG_DELEGATION]);
+    let type_id = [7u8; 32];
+
+    // (delegation cell, delegated by the passport, signed by the session key, certificate type
+    // id, spend cap, outputs to another lock, error)
+    let cases = vec![
+        (true, true, true, type_id, Some(500), 500, None),
+        (true, true, true, type_id, Some(500), 501, Some(DELEGATION_CAP_EXCEEDED)),
+        (true, true, true, type_id, None, 1300, None),
+        (false, true, true, type_id, None, 1300, Some(DELEGATION_MISSING)),
+        (true, true, false, type_id, None, 1300, Some(DELEGATION_MISSING)),
+        (true, true, true, [8u8; 32], None, 1300, Some(DELEGATION_MISSING)),
+        (true, false, true, type_id, None, 1300, Some(WRONG_PUB_KEY)),
+    ];
+    for (delegated, by_passport, by_session_key, certificate_type_id, spend_cap, other_capacity, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let other_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let (lock_script, mut cell_deps) = deploy_passport_lock(&mut context, args.clone());
+        if delegated {
+            let (signing_key, signing_public_key) = if by_passport {
+                (&private_key, &public_key)
+            } else {
+                (&other_private_key, &other_public_key)
+            };
+            let (output, data) = build_delegation_cell(
+                &lock_script,
+                signing_key,
+                signing_public_key,
+                &session_key,
+                type_id,
+                certificate_type_id,
+                spend_cap,
+            );
+            let out_point = context.create_cell(output, data);
+            cell_deps.push(CellDep::new_builder().out_point(out_point).build());
+        }
+        let outputs = vec![
+            (lock_script.clone(), 1300 - other_capacity, Bytes::new()),
+            (other_lock, other_capacity, Bytes::new()),
+        ];
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, Bytes::new(), 0, outputs);
+        let tx = if by_session_key {
+            sign_tx_secp256k1(tx, &session_key)
+        } else {
+            sign_tx_secp256k1(tx, &Generator::random_privkey())
+        };
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_session_key_expiry() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (_, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let session_key = Generator::random_privkey();
+    let mut args = public_key_hash;
+    args.extend_from_slice(&[0x80, FLAG_DELEGATION]);
+
+    // (input since, capacity back to the passport lock, error)
+    let cases = vec![
+        (DELEGATION_EXPIRY, 1000, None),
+        (DELEGATION_EXPIRY - 1, 1000, Some(SINCE_NOT_SATISFIED)),
+        (DELEGATION_EXPIRY, 999, Some(DELEGATION_OUTPUT_MISMATCH)),
+    ];
+    for (since, capacity, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let other_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let (lock_script, cell_deps) = deploy_passport_lock(&mut context, args.clone());
+        let type_id = [7u8; 32];
+        let (output, data) =
+            build_delegation_cell(&lock_script, &private_key, &public_key, &session_key, type_id, type_id, None);
+        let out_point = context.create_cell(output, data);
+
+        // anyone expires the delegation cell without a signature
+        let tx = TransactionBuilder::default()
+            .input(CellInput::new_builder().previous_output(out_point).since(since.pack()).build())
+            .output(CellOutput::new_builder().capacity(capacity.pack()).lock(lock_script.clone()).build())
+            .output(CellOutput::new_builder().capacity((1000 - capacity).pack()).lock(other_lock).build())
+            .outputs_data(vec![Bytes::new(); 2].pack())
+            .cell_deps(cell_deps)
+            .build();
+        let tx = context.complete_tx(tx);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+// DER encoding of an element of the passive authentication data
+fn der(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut encoding = vec![tag];
+    if content.len() < 0x80 {
+        encoding.push(content.len() as u8);
+    } else {
+        let len: Vec<u8> = content.len().to_be_bytes().iter().cloned().skip_while(|byte| *byte == 0).collect();
+        encoding.push(0x80 | len.len() as u8);
+        encoding.extend(len);
+    }
+    encoding.extend_from_slice(content);
+    encoding
+}
+
+// EF.DG15 of an active authentication key
+fn build_dg15(public_key: &PKey<Public>) -> Vec<u8> {
+    der(0x6F, &public_key.public_key_to_der().unwrap())
+}
+
+// LDSSecurityObject ::= SEQUENCE { version, hashAlgorithm, dataGroupHashValues } with the
+// SHA-256 hashes of DG1 and DG15
+fn build_security_object(dg1: &[u8], dg15: &[u8]) -> Vec<u8> {
+    let sha256_oid = der(0x06, &[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01]);
+    let data_group_hash = |number: u8, data: &[u8]| {
+        let hash = hash(MessageDigest::sha256(), data).unwrap();
+        der(0x30, &[der(0x02, &[number]), der(0x04, &hash)].concat())
+    };
+    let hashes = [data_group_hash(1, dg1), data_group_hash(15, dg15)].concat();
+    der(0x30, &[der(0x02, &[0]), der(0x30, &sha256_oid), der(0x30, &hashes)].concat())
+}
+
+// id-icao-ldsSecurityObject and id-data
+const OID_LDS_SECURITY_OBJECT: [u8; 6] = [0x67, 0x81, 0x08, 0x01, 0x01, 0x01];
+const OID_DATA: [u8; 9] = [0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07, 0x01];
+
+// DER encodings of the elements in the content of a DER element
+fn der_children(encoding: &[u8]) -> Vec<&[u8]> {
+    let read_header = |data: &[u8]| {
+        if data[1] & 0x80 == 0 {
+            (2, data[1] as usize)
+        } else {
+            let count = (data[1] & 0x7F) as usize;
+            (2 + count, data[2..2 + count].iter().fold(0, |len, byte| (len << 8) | *byte as usize))
+        }
+    };
+    let (header_len, len) = read_header(encoding);
+    let mut content = &encoding[header_len..header_len + len];
+    let mut children = Vec::new();
+    while !content.is_empty() {
+        let (header_len, len) = read_header(content);
+        children.push(&content[..header_len + len]);
+        content = &content[header_len + len..];
+    }
+    children
+}
+
+// An EF.SOD of the security object, signed by a document signer whose certificate the CSCA
+// signs. OpenSSL only signs CMS of id-data content, so the SignedData is built by hand with
+// the eContentType and the signed contentType attribute given.
+fn build_sod(csca_key: &PKey<Private>, security_object: &[u8], content_type: &[u8], signed_content_type: &[u8]) -> Vec<u8> {
+    let (ds_key, ds_public_key) = generate_random_key(2048);
+    let build_name = |common_name: &str| {
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("C", "UT").unwrap();
+        name.append_entry_by_text("CN", common_name).unwrap();
+        name.build()
+    };
+    let mut certificate = X509Builder::new().unwrap();
+    certificate.set_version(2).unwrap();
+    certificate
+        .set_serial_number(&BigNum::from_u32(1).unwrap().to_asn1_integer().unwrap())
+        .unwrap();
+    certificate.set_issuer_name(&build_name("CSCA")).unwrap();
+    certificate.set_subject_name(&build_name("Document Signer")).unwrap();
+    certificate.set_not_before(&Asn1Time::days_from_now(0).unwrap()).unwrap();
+    certificate.set_not_after(&Asn1Time::days_from_now(3650).unwrap()).unwrap();
+    certificate.set_pubkey(&ds_public_key).unwrap();
+    certificate.sign(csca_key, MessageDigest::sha256()).unwrap();
+    let certificate = certificate.build().to_der().unwrap();
+
+    // version, serialNumber, signature and issuer of the TBSCertificate
+    let tbs_fields = der_children(der_children(&certificate)[0]);
+
+    let oid = |content: &[u8]| der(0x06, content);
+    let sha256 = der(0x30, &oid(&[0x60, 0x86, 0x48, 0x01, 0x65, 0x03, 0x04, 0x02, 0x01]));
+    let attribute = |attr_type: &[u8], value: Vec<u8>| der(0x30, &[oid(attr_type), der(0x31, &value)].concat());
+    let signed_attributes = [
+        attribute(&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x03], oid(signed_content_type)),
+        attribute(
+            &[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x09, 0x04],
+            der(0x04, &hash(MessageDigest::sha256(), security_object).unwrap()),
+        ),
+    ]
+    .concat();
+    let mut signer = Signer::new(MessageDigest::sha256(), &ds_key).unwrap();
+    signer.update(&der(0x31, &signed_attributes)).unwrap();
+    let signature = signer.sign_to_vec().unwrap();
+
+    let signer_info = der(
+        0x30,
+        &[
+            der(0x02, &[1]),
+            der(0x30, &[tbs_fields[3], tbs_fields[1]].concat()),
+            sha256.clone(),
+            der(0xA0, &signed_attributes),
+            der(0x30, &[oid(&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x01]), der(0x05, &[])].concat()),
+            der(0x04, &signature),
+        ]
+        .concat(),
+    );
+    let signed_data = der(
+        0x30,
+        &[
+            der(0x02, &[3]),
+            der(0x31, &sha256),
+            der(0x30, &[oid(content_type), der(0xA0, &der(0x04, security_object))].concat()),
+            der(0xA0, &certificate),
+            der(0x31, &signer_info),
+        ]
+        .concat(),
+    );
+    let content_info = der(
+        0x30,
+        &[oid(&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x07, 0x02]), der(0xA0, &signed_data)].concat(),
+    );
+    der(0x77, &content_info)
+}
+
+#[test]
+fn test_passive_auth_registration() {
+    let (csca_key, csca_public_key) = generate_random_key(2048);
+    let (_, other_csca_public_key) = generate_random_key(2048);
+    let (_, aa_public_key) = generate_random_key(1024);
+    let (_, other_aa_public_key) = generate_random_key(1024);
+    let dg15 = build_dg15(&aa_public_key);
+    let security_object = build_security_object(DG1, &dg15);
+    let sod = build_sod(&csca_key, &security_object, &OID_LDS_SECURITY_OBJECT, &OID_LDS_SECURITY_OBJECT);
+    let data_sod = build_sod(&csca_key, &security_object, &OID_DATA, &OID_LDS_SECURITY_OBJECT);
+    let signed_data_sod = build_sod(&csca_key, &security_object, &OID_LDS_SECURITY_OBJECT, &OID_DATA);
+    let header = [ISO9796_2_ALGORITHM_ID, get_key_size(&aa_public_key), ISO9796_2_PADDING, ISO9796_2_MD_SHA1];
+    let (_, public_key_hash) = compute_pub_key_hash(&aa_public_key, ISO9796_2_MD_SHA1, false);
+    let (_, other_public_key_hash) = compute_pub_key_hash(&other_aa_public_key, ISO9796_2_MD_SHA1, false);
+    // registrations which also commit to the security object, for the document binding
+    let mut document_registration = public_key_hash.clone();
+    document_registration.extend_from_slice(&blake2b_256(&security_object));
+    let mut other_document_registration = public_key_hash.clone();
+    other_document_registration.extend_from_slice(&blake2b_256(&build_security_object(b"P<UTO", &dg15)));
+
+    // (master list CSCA, SOD, DG15, registration data, error)
+    let cases = vec![
+        (&csca_public_key, &sod, dg15.clone(), public_key_hash.clone(), None),
+        (&csca_public_key, &sod, dg15.clone(), document_registration, None),
+        (&other_csca_public_key, &sod, dg15.clone(), public_key_hash.clone(), Some(PASSIVE_AUTH_CERTIFICATE_VERIFY_ERROR)),
+        (&csca_public_key, &sod, build_dg15(&other_aa_public_key), other_public_key_hash.clone(), Some(PASSIVE_AUTH_DATA_GROUP_HASH_MISMATCH)),
+        (&csca_public_key, &sod, dg15.clone(), other_public_key_hash, Some(PASSIVE_AUTH_AA_KEY_MISMATCH)),
+        (&csca_public_key, &sod, dg15.clone(), other_document_registration, Some(PASSIVE_AUTH_SECURITY_OBJECT_MISMATCH)),
+        (&csca_public_key, &data_sod, dg15.clone(), public_key_hash.clone(), Some(PASSIVE_AUTH_CONTENT_TYPE_MISMATCH)),
+        (&csca_public_key, &signed_data_sod, dg15, public_key_hash.clone(), Some(PASSIVE_AUTH_CONTENT_TYPE_MISMATCH)),
+    ];
+    for (csca, sod, dg15, registration_data, error) in cases {
+        let mut context = Context::default();
+        let (_, cell_deps) = deploy_passport_lock(&mut context, vec![0u8; 20]);
+        let passive_auth_bin: Bytes = Loader::default().load_binary("ckb-passport-passive-auth");
+        let passive_auth_out_point = context.deploy_cell(passive_auth_bin);
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+        let master_list_type = context
+            .build_script(&always_success_out_point, Bytes::from(vec![2u8]))
+            .expect("script");
+        let master_list_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(lock.clone())
+                .type_(Some(master_list_type.clone()).pack())
+                .build(),
+            Bytes::from(passive_auth::master_list_data(&[csca.public_key_to_der().unwrap()])),
+        );
+        let registration_type = context
+            .build_script(&passive_auth_out_point, master_list_type.calc_script_hash().as_bytes())
+            .expect("script");
+
+        let input_out_point = context.create_cell(
+            CellOutput::new_builder().capacity(1000u64.pack()).lock(lock.clone()).build(),
+            Bytes::new(),
+        );
+        let witness = WitnessArgs::new_builder()
+            .output_type(Some(Bytes::from(passive_auth::witness(header, sod, &dg15, 0))).pack())
+            .build();
+        let tx = TransactionBuilder::default()
+            .input(CellInput::new_builder().previous_output(input_out_point).build())
+            .output(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(lock)
+                    .type_(Some(registration_type).pack())
+                    .build(),
+            )
+            .output_data(Bytes::from(registration_data).pack())
+            .cell_deps(cell_deps)
+            .cell_dep(CellDep::new_builder().out_point(passive_auth_out_point).build())
+            .cell_dep(CellDep::new_builder().out_point(master_list_out_point).build())
+            .witness(witness.as_bytes().pack())
+            .build();
+        let tx = context.complete_tx(tx);
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).output_type_script(0)));
+    }
+}
+
+#[test]
+fn test_passive_auth_lock() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let (_, other_public_key_hash) = compute_pub_key_hash(&generate_random_key(1024).1, ISO9796_2_MD_SHA1, false);
+
+    // (registration cell data, error)
+    let cases = vec![
+        (Some(public_key_hash.clone()), None),
+        (Some(other_public_key_hash), Some(PASSPORT_NOT_REGISTERED)),
+        (None, Some(PASSPORT_NOT_REGISTERED)),
+    ];
+    for (registration_data, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let registration_type = context
+            .build_script(&always_success_out_point, Bytes::from(vec![3u8]))
+            .expect("script");
+        let registration_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+
+        let mut args = public_key_hash.clone();
+        args.extend_from_slice(&[0x80, FLAG_PASSIVE_AUTH]);
+        args.extend_from_slice(registration_type.calc_script_hash().as_slice());
+        let (lock_script, mut cell_deps) = deploy_passport_lock(&mut context, args);
+        if let Some(registration_data) = registration_data {
+            let registration_out_point = context.create_cell(
+                CellOutput::new_builder()
+                    .capacity(1000u64.pack())
+                    .lock(registration_lock.clone())
+                    .type_(Some(registration_type.clone()).pack())
+                    .build(),
+                Bytes::from(registration_data),
+            );
+            cell_deps.push(CellDep::new_builder().out_point(registration_out_point).build());
+        }
+        let outputs = vec![(registration_lock.clone(), 1300, Bytes::new())];
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, Bytes::new(), 0, outputs);
+        let tx = sign_tx_with_lock(
+            tx,
+            get_key_len(&public_key),
+            SUB_SIGNATURE_COUNT,
+            |message| iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, message),
+            |signatures| {
+                let mut lock = signatures.concat();
+                lock.extend_from_slice(&rsa_info);
+                lock
+            },
+        );
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}
+
+#[test]
+fn test_document_reveal() {
+    let (private_key, public_key) = generate_random_key(1024);
+    let (rsa_info, public_key_hash) = compute_pub_key_hash(&public_key, ISO9796_2_MD_SHA1, false);
+    let mut passport_hash = [0u8; 20];
+    passport_hash.copy_from_slice(&public_key_hash);
+    let (_, aa_public_key) = generate_random_key(1024);
+    let dg15 = build_dg15(&aa_public_key);
+    let security_object = build_security_object(DG1, &dg15);
+    let other_dg1 = b"P<UTOERIKSSON<<ANNA";
+    let other_security_object = build_security_object(other_dg1, &dg15);
+    let salt = [7u8; 32];
+    let other_salt = [8u8; 32];
+
+    // (revealed salt, revealed DG1, registered security object, error)
+    let cases = vec![
+        (salt, &DG1[..], Some(&security_object), None),
+        (other_salt, &DG1[..], Some(&security_object), Some(DOCUMENT_MISMATCH)),
+        (salt, &other_dg1[..], Some(&security_object), Some(DOCUMENT_MISMATCH)),
+        (salt, &DG1[..], Some(&other_security_object), Some(PASSPORT_NOT_REGISTERED)),
+        (salt, &DG1[..], None, Some(PASSPORT_NOT_REGISTERED)),
+    ];
+    for (revealed_salt, revealed_dg1, registered_security_object, error) in cases {
+        let mut context = Context::default();
+        let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+        let registration_type = context
+            .build_script(&always_success_out_point, Bytes::from(vec![3u8]))
+            .expect("script");
+        let registration_lock = context
+            .build_script(&always_success_out_point, Bytes::new())
+            .expect("script");
+
+        let mut args = public_key_hash.clone();
+        args.extend_from_slice(&[0x80, 0x80 | FLAG_PASSIVE_AUTH, FLAG_DOCUMENT | FLAG_DOCUMENT_REVEAL]);
+        args.extend_from_slice(registration_type.calc_script_hash().as_slice());
+        args.extend_from_slice(&document::commitment(&salt, revealed_dg1));
+        let (lock_script, mut cell_deps) = deploy_passport_lock(&mut context, args);
+        let registration_data = passive_auth::registration_data(
+            &passport_hash,
+            registered_security_object.map(|security_object| &security_object[..]),
+        );
+        let registration_out_point = context.create_cell(
+            CellOutput::new_builder()
+                .capacity(1000u64.pack())
+                .lock(registration_lock.clone())
+                .type_(Some(registration_type.clone()).pack())
+                .build(),
+            Bytes::from(registration_data),
+        );
+        cell_deps.push(CellDep::new_builder().out_point(registration_out_point).build());
+        let outputs = vec![(registration_lock.clone(), 1300, Bytes::new())];
+        let tx = build_spend_tx(&mut context, &lock_script, cell_deps, Bytes::new(), 0, outputs);
+        let tx = sign_tx_with_lock(
+            tx,
+            get_key_len(&public_key),
+            SUB_SIGNATURE_COUNT,
+            |message| iso9796_2_sign(&private_key, ISO9796_2_MD_SHA1, message),
+            |signatures| {
+                let mut lock = signatures.concat();
+                lock.extend_from_slice(&rsa_info);
+                document::witness_lock(&revealed_salt, revealed_dg1, &security_object, &lock)
+            },
+        );
+
+        // run
+        let result = context.verify_tx(&tx, MAX_CYCLES);
+        assert_result(result, error.map(|error| ScriptError::ValidationFailure(error).input_lock_script(0)));
+    }
+}